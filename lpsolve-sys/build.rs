@@ -1,65 +1,261 @@
-extern crate gcc;
+extern crate cc;
+extern crate pkg_config;
+#[cfg(feature = "bindgen")]
+extern crate bindgen;
 
 use std::env;
+use std::fs;
+use std::path::Path;
 
-fn main() {
-    let mut cfg = gcc::Build::new();
-    cfg.include("lp_solve_5.5")
-       .include("lp_solve_5.5/bfp")
-       .include("lp_solve_5.5/bfp/bfp_LUSOL")
-       .include("lp_solve_5.5/bfp/bfp_LUSOL/LUSOL")
-       .include("lp_solve_5.5/colamd")
-       .include("lp_solve_5.5/shared")
+const INCLUDE_DIRS: &[&str] = &[
+    "lp_solve_5.5",
+    "lp_solve_5.5/bfp",
+    "lp_solve_5.5/bfp/bfp_LUSOL",
+    "lp_solve_5.5/bfp/bfp_LUSOL/LUSOL",
+    "lp_solve_5.5/colamd",
+    "lp_solve_5.5/shared",
+];
+
+const SOURCES: &[&str] = &[
+    "lp_solve_5.5/lp_MDO.c",
+    "lp_solve_5.5/shared/commonlib.c",
+    "lp_solve_5.5/colamd/colamd.c",
+    "lp_solve_5.5/shared/mmio.c",
+    "lp_solve_5.5/shared/myblas.c",
+    "lp_solve_5.5/ini.c",
+    "lp_solve_5.5/fortify.c",
+    "lp_solve_5.5/lp_rlp.c",
+    "lp_solve_5.5/lp_crash.c",
+    "lp_solve_5.5/bfp/bfp_LUSOL/lp_LUSOL.c",
+    "lp_solve_5.5/bfp/bfp_LUSOL/LUSOL/lusol.c",
+    "lp_solve_5.5/lp_Hash.c",
+    "lp_solve_5.5/lp_lib.c",
+    "lp_solve_5.5/lp_wlp.c",
+    "lp_solve_5.5/lp_matrix.c",
+    "lp_solve_5.5/lp_mipbb.c",
+    "lp_solve_5.5/lp_MPS.c",
+    "lp_solve_5.5/lp_params.c",
+    "lp_solve_5.5/lp_presolve.c",
+    "lp_solve_5.5/lp_price.c",
+    "lp_solve_5.5/lp_pricePSE.c",
+    "lp_solve_5.5/lp_report.c",
+    "lp_solve_5.5/lp_scale.c",
+    "lp_solve_5.5/lp_simplex.c",
+    "lp_solve_5.5/lp_SOS.c",
+    "lp_solve_5.5/lp_utils.c",
+    "lp_solve_5.5/yacc_read.c",
+];
+
+/// Cargo features that switch on a compile-time option of lp_solve, by the name cargo gives
+/// them in `CARGO_FEATURE_*`.
+const FEATURE_DEFINES: &[(&str, &str)] = &[
+    ("PARANOIA", "Paranoia"),
+    ("NOISNAN", "NOISNAN"),
+    ("FORTIFY", "FORTIFY"),
+];
+
+fn target_os() -> String {
+    env::var("CARGO_CFG_TARGET_OS").unwrap_or_default()
+}
+
+fn target_is_unix() -> bool {
+    env::var("CARGO_CFG_TARGET_FAMILY").map(|f| f.split(',').any(|f| f == "unix")).unwrap_or(false)
+}
+
+/// Fortify keeps a global list of allocations, which needs a lock once Rust calls in from several
+/// threads. `fortify_support.c` provides one with pthreads.
+fn fortify_threads() -> bool {
+    env::var_os("CARGO_FEATURE_FORTIFY").is_some() && target_is_unix()
+}
+
+/// Preprocessor definitions for the target, shared by everything that reads the headers.
+fn defines() -> Vec<(&'static str, Option<&'static str>)> {
+    let mut defines = vec![
+        ("INVERSE_ACTIVE", Some("INVERSE_LUSOL")),
+        ("RoleIsExternalInvEngine", None),
+        ("YY_NEVER_INTERACTIVE", None),
+        ("PARSER_LP", None),
+    ];
+    match &*target_os() {
+        "windows" => defines.push(("WIN32", None)),
+        // wasi has no dlopen, no ftime and only an emulated signal.h.
+        "wasi" => {
+            defines.push(("LoadInverseLib", Some("FALSE")));
+            defines.push(("LoadLanguageLib", Some("FALSE")));
+            defines.push(("INTEGERTIME", None));
+            defines.push(("_WASI_EMULATED_SIGNAL", None));
+        }
+        _ => {}
+    }
+    for &(feature, define) in FEATURE_DEFINES {
+        if env::var_os(format!("CARGO_FEATURE_{}", feature)).is_some() {
+            defines.push((define, None));
+        }
+    }
+    if env::var_os("CARGO_FEATURE_FORTIFY").is_some() {
+        // Without this, fortify.h declares its functions K&R style, which clashes with fortify.c.
+        defines.push(("ANSI_PROTOTYPES", Some("1")));
+    }
+    if fortify_threads() {
+        defines.push(("FORTIFY_THREADS", None));
+    }
+    defines
+}
+
+/// Links the system libraries a static lp_solve needs.
+fn link_system_libs() {
+    let os = target_os();
+    if target_is_unix() {
+        println!("cargo:rustc-link-lib=m");
+        // dlopen lives in libc on the BSDs.
+        if os == "linux" || os == "android" || os == "macos" || os == "ios" {
+            println!("cargo:rustc-link-lib=dl");
+        }
+    }
+}
+
+/// Returns `true` if `LPSOLVE_STATIC` asks for a static library.
+fn want_static() -> bool {
+    match env::var("LPSOLVE_STATIC") {
+        Ok(value) => value != "0" && !value.is_empty(),
+        Err(_) => false,
+    }
+}
+
+/// Links against `liblpsolve55` in `dir`, as given by `LPSOLVE_LIB_DIR`.
+fn link_from_dir(dir: &str) {
+    if !Path::new(dir).is_dir() {
+        panic!("LPSOLVE_LIB_DIR is set to {:?}, which is not a directory", dir);
+    }
+    println!("cargo:rustc-link-search=native={}", dir);
+    if want_static() {
+        println!("cargo:rustc-link-lib=static=lpsolve55");
+        // A static liblpsolve55 leaves its own dependencies to the final link.
+        link_system_libs();
+    } else {
+        println!("cargo:rustc-link-lib=dylib=lpsolve55");
+    }
+}
+
+/// Looks for `liblpsolve55` with pkg-config, which emits the link directives itself.
+fn probe_system() -> bool {
+    match pkg_config::Config::new().statik(want_static()).probe("lpsolve55") {
+        Ok(_) => true,
+        Err(e) => {
+            let reason = e.to_string().replace('\n', " ");
+            println!("cargo:warning=lpsolve55 not found with pkg-config, building the bundled copy: {}", reason);
+            false
+        }
+    }
+}
+
+/// A compiler configured with the include paths and definitions of the bundled sources.
+fn compiler() -> cc::Build {
+    let mut cfg = cc::Build::new();
+    cfg.includes(INCLUDE_DIRS);
+    for (name, value) in defines() {
+        cfg.define(name, value);
+    }
+    cfg
+}
+
+fn build_bundled() {
+    let mut cfg = compiler();
+    cfg.files(SOURCES)
        .opt_level(3)
-       .define("NOISNAN", None)
-       .define("INVERSE_ACTIVE", Some("INVERSE_LUSOL"))
-       .define("RoleIsExternalInvEngine", None)
-       .define("YY_NEVER_INTERACTIVE", None)
-       .define("PARSER_LP", None)
-       .file("lp_solve_5.5/lp_MDO.c")
-       .file("lp_solve_5.5/shared/commonlib.c")
-       .file("lp_solve_5.5/colamd/colamd.c")
-       .file("lp_solve_5.5/shared/mmio.c")
-       .file("lp_solve_5.5/shared/myblas.c")
-       .file("lp_solve_5.5/ini.c")
-       .file("lp_solve_5.5/fortify.c")
-       .file("lp_solve_5.5/lp_rlp.c")
-       .file("lp_solve_5.5/lp_crash.c")
-       .file("lp_solve_5.5/bfp/bfp_LUSOL/lp_LUSOL.c")
-       .file("lp_solve_5.5/bfp/bfp_LUSOL/LUSOL/lusol.c")
-       .file("lp_solve_5.5/lp_Hash.c")
-       .file("lp_solve_5.5/lp_lib.c")
-       .file("lp_solve_5.5/lp_wlp.c")
-       .file("lp_solve_5.5/lp_matrix.c")
-       .file("lp_solve_5.5/lp_mipbb.c")
-       .file("lp_solve_5.5/lp_MPS.c")
-       .file("lp_solve_5.5/lp_params.c")
-       .file("lp_solve_5.5/lp_presolve.c")
-       .file("lp_solve_5.5/lp_price.c")
-       .file("lp_solve_5.5/lp_pricePSE.c")
-       .file("lp_solve_5.5/lp_report.c")
-       .file("lp_solve_5.5/lp_scale.c")
-       .file("lp_solve_5.5/lp_simplex.c")
-       .file("lp_solve_5.5/lp_SOS.c")
-       .file("lp_solve_5.5/lp_utils.c")
-       .file("lp_solve_5.5/yacc_read.c");
-
-    let target_os = env::var("CARGO_CFG_TARGET_OS");
-    match target_os.as_ref().map(|x| &**x) {
-        Ok("linux") | Ok("freebsd") | Ok("openbsd") | Ok("netbsd") => {}
-        Ok("android") => {}
-        Ok("macos") => {}
-        Ok("ios") => {}
-        Ok("windows") => {
-            cfg.define("WIN32", None);
+       // lp_solve doesn't build cleanly with -Wall -Wextra, and its warnings aren't ours to fix.
+       .warnings(false)
+       .flag_if_supported("-Wno-deprecated-declarations");
+    // Both options turn library functions into macros, which breaks the system headers that
+    // declare them if those are included afterwards.
+    if !cfg.get_compiler().is_like_msvc() {
+        if env::var_os("CARGO_FEATURE_NOISNAN").is_some() {
+            cfg.flag("-include").flag("math.h");
         }
-        tos => panic!("unknown target os {:?}!", tos)
+        if env::var_os("CARGO_FEATURE_FORTIFY").is_some() && target_is_unix() {
+            cfg.flag("-include").flag("unistd.h");
+        }
+    }
+    if env::var_os("CARGO_FEATURE_FORTIFY").is_some() {
+        cfg.file("fortify_support.c");
+    }
+    cfg.compile("lpsolve");
+    link_system_libs();
+}
+
+/// Compiles the tables of `lprec` and SOS record offsets that the layout tests compare the
+/// bindings against, if the `layout-test` feature asks for it.
+fn build_layout() {
+    if env::var_os("CARGO_FEATURE_LAYOUT_TEST").is_some() {
+        compiler().file("layout.c").compile("lpsolve_sys_layout");
     }
+}
 
-    if !std::env::var("TARGET").unwrap().contains("msvc") {
-        cfg.flag("-lm");
-        cfg.flag("-ldl");
+/// Generates `$OUT_DIR/bindings.rs` from `lp_lib.h`, replacing the checked-in `src/bindings.rs`.
+#[cfg(feature = "bindgen")]
+fn generate_bindings() {
+    let mut builder = bindgen::Builder::default()
+        .header("lp_solve_5.5/lp_lib.h")
+        .allowlist_file(".*lp_solve_5\\.5/.*")
+        .use_core()
+        .ctypes_prefix("libc")
+        // lp_lib.h declares its functions and callbacks `__WINAPI`, which is stdcall on 32-bit
+        // Windows and the C convention everywhere else: Rust's "system" ABI. Without this, bindgen
+        // spells out the convention of the target it runs on.
+        .override_abi(bindgen::Abi::System, ".*")
+        .default_macro_constant_type(bindgen::MacroTypeVariation::Signed);
+    for dir in INCLUDE_DIRS {
+        builder = builder.clang_arg(format!("-I{}", dir));
+    }
+    for (name, value) in defines() {
+        builder = builder.clang_arg(match value {
+            Some(value) => format!("-D{}={}", name, value),
+            None => format!("-D{}", name),
+        });
     }
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("bindings.rs");
+    builder.generate()
+        .expect("failed to generate bindings for lp_lib.h")
+        .write_to_file(out)
+        .expect("failed to write the generated bindings");
+}
+
+#[cfg(not(feature = "bindgen"))]
+fn generate_bindings() {}
 
-    cfg.compile("liblpsolve.a");
+/// Writes `$OUT_DIR/build_info.rs`, which records how lp_solve ended up being linked. The
+/// definitions are only known for the bundled copy.
+fn write_build_info(linkage: &str, statik: bool, defines: &[(&str, Option<&str>)]) {
+    let contents = format!(
+        "pub const BUILD_LINKAGE: &str = {:?};\n\
+         pub const BUILD_STATIC: bool = {:?};\n\
+         pub const BUILD_DEFINES: &[(&str, Option<&str>)] = &{:?};\n",
+        linkage, statik, defines);
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("build_info.rs");
+    fs::write(out, contents).expect("failed to write build_info.rs");
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=lp_solve_5.5");
+    println!("cargo:rerun-if-changed=layout.c");
+    println!("cargo:rerun-if-changed=fortify_support.c");
+    println!("cargo:rerun-if-env-changed=LPSOLVE_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=LPSOLVE_STATIC");
+
+    generate_bindings();
+    build_layout();
+
+    // An explicit directory wins over pkg-config, which wins over the bundled sources.
+    if let Ok(dir) = env::var("LPSOLVE_LIB_DIR") {
+        link_from_dir(&dir);
+        write_build_info("system", want_static(), &[]);
+        return;
+    }
+    if env::var_os("CARGO_FEATURE_SYSTEM").is_some() && probe_system() {
+        write_build_info("system", want_static(), &[]);
+        return;
+    }
+    build_bundled();
+    write_build_info("bundled", true, &defines());
 }
@@ -0,0 +1,40 @@
+/* The parts of Fortify that the program using it has to supply. */
+#include <stdlib.h>
+
+/* Called when Fortify can't continue, for example if FORTIFY_OUTPUT names a file it can't create. */
+int EndOfPgr(int code)
+{
+    exit(code);
+    return code;
+}
+
+#ifdef FORTIFY_THREADS
+/* The lock around Fortify's list of allocations. It has to be recursive, since Fortify checks all
+ * memory from inside its own locked sections. */
+#include <pthread.h>
+
+static pthread_mutex_t fortify_mutex;
+static pthread_once_t fortify_once = PTHREAD_ONCE_INIT;
+
+static void fortify_init(void)
+{
+    pthread_mutexattr_t attr;
+
+    pthread_mutexattr_init(&attr);
+    pthread_mutexattr_settype(&attr, PTHREAD_MUTEX_RECURSIVE);
+    pthread_mutex_init(&fortify_mutex, &attr);
+    pthread_mutexattr_destroy(&attr);
+}
+
+void Fortify_Lock(void)
+{
+    pthread_once(&fortify_once, fortify_init);
+    pthread_mutex_lock(&fortify_mutex);
+}
+
+void Fortify_Unlock(void)
+{
+    /* Some of Fortify's error paths unlock without locking, which a recursive mutex rejects. */
+    pthread_mutex_unlock(&fortify_mutex);
+}
+#endif
@@ -0,0 +1,32 @@
+/* Layout of lprec and the SOS records as the C compiler sees it, checked against the Rust
+   bindings by the tests. */
+#include <stddef.h>
+#include "lp_lib.h"
+
+const size_t lpsolve_sys_lprec_layout[] = {
+    sizeof(lprec),
+    offsetof(lprec, add_column),
+    offsetof(lprec, lp_name),
+    offsetof(lprec, rows),
+    offsetof(lprec, columns),
+    offsetof(lprec, orig_rhs),
+    offsetof(lprec, best_solution),
+    offsetof(lprec, bb_level),
+    offsetof(lprec, epsprimal),
+    offsetof(lprec, rowcol_name),
+    offsetof(lprec, SOS),
+};
+
+/* The fields the lpsolve crate reads special ordered sets back from. */
+const size_t lpsolve_sys_sos_layout[] = {
+    sizeof(SOSgroup),
+    offsetof(SOSgroup, sos_list),
+    offsetof(SOSgroup, sos_count),
+    sizeof(SOSrec),
+    offsetof(SOSrec, name),
+    offsetof(SOSrec, type),
+    offsetof(SOSrec, size),
+    offsetof(SOSrec, priority),
+    offsetof(SOSrec, members),
+    offsetof(SOSrec, weights),
+};
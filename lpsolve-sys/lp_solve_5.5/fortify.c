@@ -54,7 +54,9 @@ int _Fortify_line=0;
 # include <conio.h>
 #else
 # include <unistd.h>
-# include <termio.h>
+# ifdef FORTIFY_WAIT_FOR_KEY
+#  include <termio.h>
+# endif
 # define getch() getchar()
 #endif
 
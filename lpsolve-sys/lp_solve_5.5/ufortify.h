@@ -16,7 +16,7 @@
 # define KNOWS_POINTER_TYPE
 #endif
 
-#define FORTIFY_WAIT_FOR_KEY         /* Pause after message            */
+/* #define FORTIFY_WAIT_FOR_KEY */      /* Pause after message            */
 
 #if !defined FORTIFY_BEFORE_SIZE
 # define FORTIFY_BEFORE_SIZE     16  /* Bytes to allocate before block */
@@ -57,7 +57,14 @@
 #define WARN_ON_SIZE_T_OVERFLOW/* Watch for breaking the 64K limit in  */
                                /* some braindead architectures...      */
 
-#define FORTIFY_LOCK()
-#define FORTIFY_UNLOCK()
+#if defined FORTIFY_THREADS           /* Locking provided by the program */
+void Fortify_Lock(void);
+void Fortify_Unlock(void);
+# define FORTIFY_LOCK()   Fortify_Lock()
+# define FORTIFY_UNLOCK() Fortify_Unlock()
+#else
+# define FORTIFY_LOCK()
+# define FORTIFY_UNLOCK()
+#endif
 
 #endif
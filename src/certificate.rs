@@ -0,0 +1,363 @@
+//! Certificates of unboundedness and infeasibility.
+//!
+//! A solver's word that a problem is unbounded or infeasible can be checked if it comes with a
+//! certificate:
+//!
+//! * A problem is unbounded if it is feasible and has a ray: a direction `d` that every row and
+//!   bound allows moving along forever, and along which the objective improves.
+//! * A problem is infeasible if it has a Farkas certificate: multipliers `y` for the rows, such
+//!   that the combination `y^T A x` has to be at least some value because of the bounds on `x`,
+//!   but at most a smaller value because of the limits of the rows. A positive multiplier combines
+//!   the upper limit of its row, a negative one the lower limit.
+//!
+//! lpsolve reports the final basis of a solve that stops as unbounded or infeasible, but not the
+//! certificates, and its dual values at that point belong to whichever phase it stopped in. So
+//! `Problem::certificate` derives them from the basis returned by `get_basis`, treating row `i`
+//! as a variable `r_i` tied to the columns by `r - A x = 0`, and inverting the dense basis matrix
+//! of that system:
+//!
+//! * A ray is the edge leaving the final vertex along a nonbasic variable: that variable moves by
+//!   1 and the basic variables follow, `d_B = -B^-1 a_q`.
+//! * Farkas multipliers are a row of `B^-1`, which combines the equations into `y^T r = y^T A x`.
+//!   When the simplex stops on a basic variable that no nonbasic one can bring within its
+//!   bounds, the row of that variable proves it.
+//!
+//! Every candidate is checked with `Certificate::check`, which verifies it in Rust, independently
+//! of lpsolve, and the first one that passes is returned.
+//!
+//! Certificates are about the LP relaxation: integrality, semicontinuity and special ordered
+//! sets are ignored. Inverting the basis takes `O(m^3)` time for `m` rows.
+
+use libc::c_int;
+
+use {ModelData, Problem, SolveStatus};
+
+/// Proof that a problem is unbounded or infeasible.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Certificate {
+    /// A direction of unboundedness, with one entry per column.
+    Ray(Vec<f64>),
+    /// Farkas multipliers, with one entry per row.
+    Farkas(Vec<f64>),
+}
+
+/// `value` with lpsolve's infinity replaced by `f64::INFINITY`.
+fn unbounded(value: f64, infinity: f64) -> f64 {
+    if value >= infinity {
+        f64::INFINITY
+    } else if value <= -infinity {
+        f64::NEG_INFINITY
+    } else {
+        value
+    }
+}
+
+/// The limits of each row of `model`, with infinite ones as `f64` infinities.
+fn row_limits(model: &ModelData) -> Vec<(f64, f64)> {
+    model.rows.iter().map(|row| {
+        let (lower, upper) = row.limits(model.infinity);
+        (unbounded(lower, model.infinity), unbounded(upper, model.infinity))
+    }).collect()
+}
+
+/// The bounds of each column of `model`, with infinite ones as `f64` infinities.
+fn column_bounds(model: &ModelData) -> Vec<(f64, f64)> {
+    model.columns.iter()
+        .map(|col| (unbounded(col.lower, model.infinity), unbounded(col.upper, model.infinity)))
+        .collect()
+}
+
+/// The final basis of a solve, in the system `r - A x = 0`.
+struct Basis {
+    rows: usize,
+    /// The basic variables, in basis order. Variable `i < rows` is row `i + 1`, and variable
+    /// `rows + j` is column `j + 1`.
+    basic: Vec<usize>,
+    nonbasic: Vec<usize>,
+    /// The entries of `A` by column, as row indices and values.
+    columns: Vec<Vec<(usize, f64)>>,
+    /// The inverse of the basis matrix, by row.
+    inverse: Vec<Vec<f64>>,
+}
+
+impl Basis {
+    /// The final basis of the most recent solve of `problem`, or `None` if there is none or it is
+    /// singular.
+    fn new(problem: &Problem, model: &ModelData) -> Option<Basis> {
+        let rows = model.rows.len();
+        let mut indices = vec![0; rows + model.columns.len() + 1];
+        check!(problem.get_basis(&mut indices, true));
+        let variable = |&index: &c_int| index.unsigned_abs() as usize - 1;
+        let basic: Vec<usize> = indices[1..=rows].iter().map(variable).collect();
+        let nonbasic = indices[rows + 1..].iter().map(variable).collect();
+
+        let mut columns = vec![Vec::new(); model.columns.len()];
+        for (i, row) in model.rows.iter().enumerate() {
+            for &(j, a) in &row.coefficients {
+                columns[j as usize - 1].push((i, a));
+            }
+        }
+        let mut basis = Basis { rows, basic, nonbasic, columns, inverse: Vec::new() };
+        let mut matrix = vec![vec![0.0; rows]; rows];
+        for (p, &var) in basis.basic.iter().enumerate() {
+            for (i, value) in basis.column(var) {
+                matrix[i][p] = value;
+            }
+        }
+        basis.inverse = invert(matrix)?;
+        Some(basis)
+    }
+
+    /// The entries of a variable's column in `[I, -A]`.
+    fn column(&self, var: usize) -> Vec<(usize, f64)> {
+        if var < self.rows {
+            vec![(var, 1.0)]
+        } else {
+            self.columns[var - self.rows].iter().map(|&(i, a)| (i, -a)).collect()
+        }
+    }
+
+    /// The change of the columns when `var` increases by 1 and the basic variables follow.
+    fn edge(&self, var: usize) -> Vec<f64> {
+        let mut direction = vec![0.0; self.columns.len()];
+        if var >= self.rows {
+            direction[var - self.rows] = 1.0;
+        }
+        let column = self.column(var);
+        for (inverse, &basic) in self.inverse.iter().zip(&self.basic) {
+            if basic >= self.rows {
+                direction[basic - self.rows] = -column.iter().map(|&(i, value)| inverse[i] * value).sum::<f64>();
+            }
+        }
+        direction
+    }
+}
+
+/// Inverts a dense square matrix by Gauss-Jordan elimination with partial pivoting, or returns
+/// `None` if it is singular.
+fn invert(mut matrix: Vec<Vec<f64>>) -> Option<Vec<Vec<f64>>> {
+    let n = matrix.len();
+    let mut inverse: Vec<Vec<f64>> = (0..n).map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect();
+    for k in 0..n {
+        let pivot = (k..n).max_by(|&a, &b| matrix[a][k].abs().partial_cmp(&matrix[b][k].abs()).unwrap())?;
+        if matrix[pivot][k].abs() <= 1e-12 {
+            return None;
+        }
+        matrix.swap(k, pivot);
+        inverse.swap(k, pivot);
+        let scale = matrix[k][k];
+        for j in 0..n {
+            matrix[k][j] /= scale;
+            inverse[k][j] /= scale;
+        }
+        for i in 0..n {
+            let factor = matrix[i][k];
+            if i == k || factor == 0.0 {
+                continue;
+            }
+            for j in 0..n {
+                matrix[i][j] -= factor * matrix[k][j];
+                inverse[i][j] -= factor * inverse[k][j];
+            }
+        }
+    }
+    Some(inverse)
+}
+
+/// `v` scaled so its largest entry is 1 in magnitude, with entries below `TOLERANCE` set to 0.
+fn normalize(v: &[f64]) -> Vec<f64> {
+    let largest = v.iter().fold(0.0f64, |max, &x| max.max(x.abs()));
+    v.iter().map(|&x| if x.abs() <= TOLERANCE * largest { 0.0 } else { x / largest }).collect()
+}
+
+/// The first of `candidates` and their negations that passes `check`, normalized.
+fn first_valid<I, F>(candidates: I, check: F) -> Option<Vec<f64>>
+    where I: IntoIterator<Item=Vec<f64>>, F: Fn(&[f64]) -> bool
+{
+    for candidate in candidates {
+        if candidate.iter().all(|&x| x == 0.0) {
+            continue;
+        }
+        for &sign in &[1.0, -1.0] {
+            let candidate = normalize(&candidate.iter().map(|&x| sign * x).collect::<Vec<_>>());
+            if check(&candidate) {
+                return Some(candidate);
+            }
+        }
+    }
+    None
+}
+
+impl Problem {
+    /// A certificate for the status of the most recent `solve`: a ray if it was `Unbounded`, a
+    /// Farkas certificate if it was `Infeasible`. Returns `None` for any other status, or if no
+    /// certificate is found in the final basis, which can happen when only the integer problem
+    /// is infeasible or unbounded.
+    pub fn certificate(&self) -> Option<Certificate> {
+        match self.get_status() {
+            Some(SolveStatus::Unbounded) => self.unbounded_ray().map(Certificate::Ray),
+            Some(SolveStatus::Infeasible) => self.farkas_certificate().map(Certificate::Farkas),
+            _ => None,
+        }
+    }
+
+    /// A direction along which the objective of the LP relaxation improves without bound, found
+    /// among the edges leaving the final basis of the most recent `solve`, and scaled so its
+    /// largest entry is 1 in magnitude.
+    ///
+    /// This only proves the problem unbounded if it is also feasible.
+    pub fn unbounded_ray(&self) -> Option<Vec<f64>> {
+        let model = ModelData::from_problem(self);
+        let basis = Basis::new(self, &model)?;
+        first_valid(basis.nonbasic.iter().map(|&var| basis.edge(var)),
+                    |d| Certificate::Ray(d.to_vec()).check(self, TOLERANCE))
+    }
+
+    /// Farkas multipliers that prove the LP relaxation infeasible, found among the rows of the
+    /// inverse of the final basis of the most recent `solve`, and scaled so the largest is 1 in
+    /// magnitude.
+    pub fn farkas_certificate(&self) -> Option<Vec<f64>> {
+        let model = ModelData::from_problem(self);
+        let basis = Basis::new(self, &model)?;
+        first_valid(basis.inverse.iter().cloned(), |y| Certificate::Farkas(y.to_vec()).check(self, TOLERANCE))
+    }
+}
+
+/// The tolerance the certificates found by `Problem` are checked with.
+const TOLERANCE: f64 = 1e-9;
+
+impl Certificate {
+    /// Verifies that the certificate proves the LP relaxation of `problem` unbounded or
+    /// infeasible, allowing for rounding errors of up to `tolerance`.
+    ///
+    /// A ray must be allowed by every bound and row and improve the objective by more than
+    /// `tolerance`. Farkas multipliers must only combine finite limits of the rows, and the
+    /// combination must be forced above them by more than `tolerance`. Entries smaller than
+    /// `tolerance` count as 0.
+    pub fn check(&self, problem: &Problem, tolerance: f64) -> bool {
+        let model = ModelData::from_problem(problem);
+        let rows = row_limits(&model);
+        let cols = column_bounds(&model);
+        let negligible = |v: f64| v.abs() <= tolerance;
+        match *self {
+            Certificate::Ray(ref d) => {
+                if d.len() != cols.len() {
+                    return false;
+                }
+                let columns_allow = d.iter().zip(&cols).all(|(&d, &(lower, upper))| {
+                    negligible(d) || (d > 0.0 && upper.is_infinite()) || (d < 0.0 && lower.is_infinite())
+                });
+                let rows_allow = model.rows.iter().zip(&rows).all(|(row, &(lower, upper))| {
+                    let change: f64 = row.coefficients.iter().map(|&(j, a)| a * d[j as usize - 1]).sum();
+                    negligible(change) || (change > 0.0 && upper.is_infinite()) || (change < 0.0 && lower.is_infinite())
+                });
+                let gain: f64 = model.objective.iter().map(|&(j, c)| c * d[j as usize - 1]).sum();
+                let gain = if model.maximize { gain } else { -gain };
+                columns_allow && rows_allow && gain > tolerance
+            }
+            Certificate::Farkas(ref y) => {
+                if y.len() != rows.len() {
+                    return false;
+                }
+                // y^T A x <= at_most because of the rows, and >= at_least because of the bounds.
+                let mut at_most = 0.0;
+                let mut r = vec![0.0; cols.len()];
+                for ((row, &(lower, upper)), &y) in model.rows.iter().zip(&rows).zip(y) {
+                    if negligible(y) {
+                        continue;
+                    }
+                    at_most += y * if y > 0.0 { upper } else { lower };
+                    for &(j, a) in &row.coefficients {
+                        r[j as usize - 1] += y * a;
+                    }
+                }
+                let mut at_least = 0.0;
+                for (&r, &(lower, upper)) in r.iter().zip(&cols) {
+                    if !negligible(r) {
+                        at_least += r * if r > 0.0 { lower } else { upper };
+                    }
+                }
+                // An infinite limit or bound makes one of the sums infinite, or NaN.
+                at_least.is_finite() && at_most.is_finite() && at_least - at_most > tolerance
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {lp_format, SolveStatus};
+    use super::Certificate;
+
+    #[test]
+    fn ray() {
+        let mut problem = lp_format::parse("
+            max: 2x + y;
+            c1: x - y <= 4;
+            c2: x + y >= 2;
+        ").unwrap().to_problem().unwrap();
+        assert_eq!(problem.solve(), SolveStatus::Unbounded);
+        let certificate = problem.certificate().unwrap();
+        assert!(certificate.check(&problem, 1e-9));
+        let d = match certificate {
+            Certificate::Ray(ref d) => d.clone(),
+            _ => panic!("expected a ray, got {:?}", certificate),
+        };
+        assert!(d[0] - d[1] <= 1e-9 && 2.0 * d[0] + d[1] > 0.0);
+
+        // Moving against c1 isn't allowed.
+        assert!(!Certificate::Ray(vec![1.0, 0.0]).check(&problem, 1e-9));
+        assert!(Certificate::Ray(vec![1.0, 1.0]).check(&problem, 1e-9));
+        assert!(!Certificate::Ray(vec![0.0, -1.0]).check(&problem, 1e-9));
+    }
+
+    #[test]
+    fn farkas() {
+        let mut problem = lp_format::parse("
+            min: x + y;
+            supply: x + y <= 10;
+            demand: x + 2y >= 30;
+            x <= 8;
+            y <= 9;
+        ").unwrap().to_problem().unwrap();
+        assert_eq!(problem.solve(), SolveStatus::Infeasible);
+        let certificate = problem.certificate().unwrap();
+        assert!(certificate.check(&problem, 1e-9));
+
+        // The demand can't be met within the bounds on its own: x + 2y <= 26 < 30.
+        assert!(Certificate::Farkas(vec![0.0, -1.0]).check(&problem, 1e-9));
+        assert!(!Certificate::Farkas(vec![1.0, 0.0]).check(&problem, 1e-9));
+        // A positive multiplier would need an upper limit on the demand.
+        assert!(!Certificate::Farkas(vec![0.0, 1.0]).check(&problem, 1e-9));
+        assert!(!Certificate::Farkas(vec![0.0]).check(&problem, 1e-9));
+
+        assert!(problem.set_rh(2, 15.0));
+        assert_eq!(problem.solve(), SolveStatus::Optimal);
+        assert_eq!(problem.certificate(), None);
+        assert_eq!(problem.farkas_certificate(), None);
+    }
+
+    #[test]
+    fn from_the_final_basis() {
+        // Neither certificate is a single row or column here.
+        let mut problem = lp_format::parse("
+            max: x + 2y;
+            c1: x - y >= -2;
+            c2: -2 <= x - 2y <= 1;
+            c3: x + y >= 1;
+        ").unwrap().to_problem().unwrap();
+        assert_eq!(problem.solve(), SolveStatus::Unbounded);
+        assert_eq!(problem.certificate(), Some(Certificate::Ray(vec![1.0, 0.5])));
+
+        // 3x >= 8 from the first two rows, but x <= 2.5.
+        let mut problem = lp_format::parse("
+            min: ;
+            c1: 2x + y >= 6;
+            c2: x - y >= 2;
+            c3: x + 3y <= 2;
+            x <= 2.5;
+        ").unwrap().to_problem().unwrap();
+        assert_eq!(problem.solve(), SolveStatus::Infeasible);
+        assert_eq!(problem.certificate(), Some(Certificate::Farkas(vec![-1.0, -1.0, 0.0])));
+    }
+}
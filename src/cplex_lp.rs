@@ -0,0 +1,869 @@
+//! Reading and writing the CPLEX LP format.
+//!
+//! This is the dialect most other tools emit, with `Maximize`/`Minimize`, `Subject To`,
+//! `Bounds`, `General`, `Binary`, `Semi-Continuous` and `SOS` sections, closed by `End`. Line
+//! breaks are insignificant except that section headers must start a line, and `\` starts a
+//! comment that runs to the end of the line.
+//!
+//! A ranged row is written as a double inequality, `r1: -5 <= x + y <= 10`, where the second
+//! operator gives the type of the row. A free row is written as `>= -inf`, which reads back as
+//! free. SOS priorities aren't part of the format; they are written
+//! in set order and read back as 1, 2, 3 and so on.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Read, Write};
+
+use libc::c_int;
+
+use {ColumnData, ConstraintType, ModelData, Problem, RowData, SOSData, SOSType};
+use lp_format::{format_number, ParseError, Span};
+
+const INFINITY: f64 = 1e30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Maximize,
+    Minimize,
+    SubjectTo,
+    Bounds,
+    General,
+    Binary,
+    SemiContinuous,
+    Sos,
+    End,
+}
+
+/// Section headers, longest first so that `subject to` isn't read as `st`.
+const HEADERS: &[(&str, Section)] = &[
+    ("semi-continuous", Section::SemiContinuous),
+    ("semicontinuous", Section::SemiContinuous),
+    ("subject to", Section::SubjectTo),
+    ("such that", Section::SubjectTo),
+    ("maximize", Section::Maximize),
+    ("maximise", Section::Maximize),
+    ("maximum", Section::Maximize),
+    ("minimize", Section::Minimize),
+    ("minimise", Section::Minimize),
+    ("minimum", Section::Minimize),
+    ("binaries", Section::Binary),
+    ("generals", Section::General),
+    ("general", Section::General),
+    ("binary", Section::Binary),
+    ("bounds", Section::Bounds),
+    ("bound", Section::Bounds),
+    ("semis", Section::SemiContinuous),
+    ("semi", Section::SemiContinuous),
+    ("s.t.", Section::SubjectTo),
+    ("st.", Section::SubjectTo),
+    ("max", Section::Maximize),
+    ("min", Section::Minimize),
+    ("gen", Section::General),
+    ("bin", Section::Binary),
+    ("sos", Section::Sos),
+    ("end", Section::End),
+    ("st", Section::SubjectTo),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Sign(bool),
+    Op(ConstraintType),
+    Colon,
+    DoubleColon,
+    Section(Section),
+    Eof,
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!\"#$%&()/,.;?@_`'{}|~[]".contains(c)
+}
+
+/// Returns `true` if `name` is read back as the same name wherever the writer puts it: it is made
+/// of name characters, doesn't start like a number and isn't a section header or an infinity.
+fn is_name(name: &str) -> bool {
+    let lower = name.to_ascii_lowercase();
+    name.chars().next().map_or(false, |c| !c.is_ascii_digit() && c != '.') && name.chars().all(is_ident_char)
+        && !HEADERS.iter().any(|&(header, _)| header == lower)
+        && lower != "inf" && lower != "infinity"
+}
+
+/// Fails with an `InvalidInput` error if `name` can't be written.
+fn check_name(name: &str) -> io::Result<()> {
+    if is_name(name) {
+        Ok(())
+    } else {
+        Err(io::Error::new(io::ErrorKind::InvalidInput, format!("`{}` can't be written as a CPLEX LP name", name)))
+    }
+}
+
+fn tokenize(src: &str) -> Result<Vec<(Token, Span)>, ParseError> {
+    let mut tokens = Vec::new();
+    let (mut line, mut line_start, mut pos) = (1, 0, 0);
+    let bytes = src.as_bytes();
+    let span = |start: usize, end: usize, line: usize, line_start: usize| {
+        Span { start, end, line, column: start - line_start + 1 }
+    };
+    while pos < src.len() {
+        let c = src[pos..].chars().next().expect("char");
+        if c == '\n' {
+            pos += 1;
+            line += 1;
+            line_start = pos;
+            continue;
+        }
+        if c.is_whitespace() {
+            pos += c.len_utf8();
+            continue;
+        }
+        if c == '\\' {
+            while pos < src.len() && bytes[pos] != b'\n' {
+                pos += 1;
+            }
+            continue;
+        }
+        let start = pos;
+        if src[line_start..start].trim().is_empty() {
+            let rest = src[start..].lines().next().unwrap_or("").to_ascii_lowercase();
+            let header = HEADERS.iter().find(|&&(name, _)| {
+                rest.starts_with(name) && rest[name.len()..].chars().next().map_or(true, |c| !is_ident_char(c))
+            });
+            if let Some(&(name, section)) = header {
+                pos += name.len();
+                tokens.push((Token::Section(section), span(start, pos, line, line_start)));
+                continue;
+            }
+        }
+        let token = match c {
+            '+' | '-' => {
+                pos += 1;
+                Token::Sign(c == '-')
+            }
+            '<' | '>' | '=' => {
+                pos += 1;
+                let next = bytes.get(pos).cloned();
+                let op = match (c, next) {
+                    ('<', _) | ('=', Some(b'<')) => ConstraintType::Le,
+                    ('>', _) | ('=', Some(b'>')) => ConstraintType::Ge,
+                    _ => ConstraintType::Eq,
+                };
+                if next == Some(b'=') || (c == '=' && (next == Some(b'<') || next == Some(b'>'))) {
+                    pos += 1;
+                }
+                Token::Op(op)
+            }
+            ':' => {
+                pos += 1;
+                if bytes.get(pos) == Some(&b':') {
+                    pos += 1;
+                    Token::DoubleColon
+                } else {
+                    Token::Colon
+                }
+            }
+            '0'..='9' | '.' => {
+                let mut end = pos;
+                while end < bytes.len() && (bytes[end].is_ascii_digit() || bytes[end] == b'.') {
+                    end += 1;
+                }
+                if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+                    let mut exp = end + 1;
+                    if exp < bytes.len() && (bytes[exp] == b'+' || bytes[exp] == b'-') {
+                        exp += 1;
+                    }
+                    if exp < bytes.len() && bytes[exp].is_ascii_digit() {
+                        end = exp;
+                        while end < bytes.len() && bytes[end].is_ascii_digit() {
+                            end += 1;
+                        }
+                    }
+                }
+                let text = &src[pos..end];
+                let value = text.parse().map_err(|_| {
+                    ParseError::new(format!("invalid number `{}`", text), span(start, end, line, line_start))
+                })?;
+                pos = end;
+                Token::Number(value)
+            }
+            c if is_ident_char(c) => {
+                let end = src[pos..].find(|c| !is_ident_char(c)).map_or(src.len(), |n| pos + n);
+                let word = &src[pos..end];
+                pos = end;
+                match word.to_ascii_lowercase().as_str() {
+                    "inf" | "infinity" => Token::Number(INFINITY),
+                    _ => Token::Ident(word.to_string()),
+                }
+            }
+            c => {
+                return Err(ParseError::new(format!("unexpected character `{}`", c),
+                                           span(start, start + c.len_utf8(), line, line_start)))
+            }
+        };
+        tokens.push((token, span(start, pos, line, line_start)));
+    }
+    tokens.push((Token::Eof, span(pos, pos, line, line_start)));
+    Ok(tokens)
+}
+
+struct Column {
+    name: String,
+    lower: f64,
+    upper: f64,
+    integer: bool,
+    semicontinuous: bool,
+}
+
+struct Parser {
+    tokens: Vec<(Token, Span)>,
+    pos: usize,
+    columns: Vec<Column>,
+    column_index: HashMap<String, c_int>,
+}
+
+impl Parser {
+    fn peek_at(&self, n: usize) -> &Token {
+        &self.tokens[(self.pos + n).min(self.tokens.len() - 1)].0
+    }
+
+    fn peek(&self) -> &Token {
+        self.peek_at(0)
+    }
+
+    fn span(&self) -> Span {
+        self.tokens[self.pos].1
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.pos].0.clone();
+        if self.pos + 1 < self.tokens.len() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn unexpected(&self, expected: &str) -> ParseError {
+        let found = match *self.peek() {
+            Token::Number(v) => format!("number `{}`", v),
+            Token::Ident(ref name) => format!("`{}`", name),
+            Token::Sign(true) => "`-`".to_string(),
+            Token::Sign(false) => "`+`".to_string(),
+            Token::Op(_) => "an operator".to_string(),
+            Token::Colon => "`:`".to_string(),
+            Token::DoubleColon => "`::`".to_string(),
+            Token::Section(_) => "a section header".to_string(),
+            Token::Eof => "end of input".to_string(),
+        };
+        ParseError::new(format!("expected {}, found {}", expected, found), self.span())
+    }
+
+    fn column(&mut self, name: &str) -> c_int {
+        if let Some(&col) = self.column_index.get(name) {
+            return col;
+        }
+        self.columns.push(Column {
+            name: name.to_string(),
+            lower: 0.0,
+            upper: INFINITY,
+            integer: false,
+            semicontinuous: false,
+        });
+        let col = self.columns.len() as c_int;
+        self.column_index.insert(name.to_string(), col);
+        col
+    }
+
+    fn at_section(&self) -> bool {
+        matches!(*self.peek(), Token::Section(_) | Token::Eof)
+    }
+
+    /// A `name:` label.
+    fn label(&mut self) -> Option<String> {
+        match (self.peek().clone(), self.peek_at(1)) {
+            (Token::Ident(name), &Token::Colon) => {
+                self.advance();
+                self.advance();
+                Some(name)
+            }
+            _ => None,
+        }
+    }
+
+    fn sign(&mut self) -> f64 {
+        let mut sign = 1.0;
+        while let Token::Sign(negative) = *self.peek() {
+            if negative {
+                sign = -sign;
+            }
+            self.advance();
+        }
+        sign
+    }
+
+    /// A signed number, including `inf` and `infinity`.
+    fn number(&mut self) -> Result<f64, ParseError> {
+        let sign = self.sign();
+        match *self.peek() {
+            Token::Number(value) => {
+                self.advance();
+                Ok(sign * value)
+            }
+            _ => Err(self.unexpected("a number")),
+        }
+    }
+
+    /// Returns `true` if the tokens ahead are `[sign] number operator`.
+    fn constant_then_operator(&self) -> bool {
+        let mut n = 0;
+        while let Token::Sign(_) = *self.peek_at(n) {
+            n += 1;
+        }
+        matches!((self.peek_at(n), self.peek_at(n + 1)), (&Token::Number(_), &Token::Op(_)))
+    }
+
+    /// A linear expression, returning the merged coefficients and the sum of the constants.
+    fn expression(&mut self) -> Result<(Vec<(c_int, f64)>, f64), ParseError> {
+        let mut coefficients: Vec<(c_int, f64)> = Vec::new();
+        let mut constant = 0.0;
+        let mut first = true;
+        loop {
+            let signed = match *self.peek() {
+                Token::Sign(_) => true,
+                Token::Number(_) | Token::Ident(_) if first => false,
+                _ => break,
+            };
+            // A label ends the expression.
+            if let (&Token::Ident(_), &Token::Colon) = (self.peek(), self.peek_at(1)) {
+                break;
+            }
+            let sign = self.sign();
+            let coefficient = match *self.peek() {
+                Token::Number(value) => {
+                    self.advance();
+                    Some(value)
+                }
+                _ => None,
+            };
+            match self.peek().clone() {
+                Token::Ident(ref name) if *self.peek_at(1) != Token::Colon => {
+                    self.advance();
+                    let col = self.column(name);
+                    let value = sign * coefficient.unwrap_or(1.0);
+                    match coefficients.iter_mut().find(|&&mut (c, _)| c == col) {
+                        Some(entry) => entry.1 += value,
+                        None => coefficients.push((col, value)),
+                    }
+                }
+                _ => match coefficient {
+                    Some(value) => constant += sign * value,
+                    None if signed => return Err(self.unexpected("a number or variable")),
+                    None => break,
+                },
+            }
+            first = false;
+        }
+        Ok((coefficients, constant))
+    }
+
+    fn operator(&mut self) -> Result<ConstraintType, ParseError> {
+        match *self.peek() {
+            Token::Op(op) => {
+                self.advance();
+                Ok(op)
+            }
+            _ => Err(self.unexpected("a relational operator")),
+        }
+    }
+
+    fn constraint(&mut self) -> Result<RowData, ParseError> {
+        let name = self.label();
+        let range = if self.constant_then_operator() {
+            let value = self.number()?;
+            Some((self.operator()?, value))
+        } else {
+            None
+        };
+        let start = self.span();
+        let (coefficients, constant) = self.expression()?;
+        if coefficients.is_empty() && constant == 0.0 {
+            return Err(ParseError::new("expected an expression".to_string(), start));
+        }
+        let kind = self.operator()?;
+        let rhs = (self.number()? - constant).clamp(-INFINITY, INFINITY);
+        let range = match range {
+            None => None,
+            Some((op, value)) => {
+                let value = value - constant;
+                let consistent = match (op, kind) {
+                    (ConstraintType::Le, ConstraintType::Le) => value <= rhs,
+                    (ConstraintType::Ge, ConstraintType::Ge) => value >= rhs,
+                    _ => false,
+                };
+                if !consistent {
+                    return Err(ParseError::new("invalid range".to_string(), start));
+                }
+                if value.abs() >= INFINITY { None } else { Some((rhs - value).abs()) }
+            }
+        };
+        // `>= -inf` is how a free row is written.
+        let kind = if kind == ConstraintType::Ge && range.is_none() && rhs <= -INFINITY {
+            ConstraintType::Free
+        } else {
+            kind
+        };
+        let coefficients = coefficients.into_iter().filter(|&(_, v)| v != 0.0).collect();
+        Ok(RowData { name, coefficients, kind, rhs, range })
+    }
+
+    fn variable(&mut self) -> Result<usize, ParseError> {
+        match self.peek().clone() {
+            Token::Ident(name) => {
+                self.advance();
+                Ok(self.column(&name) as usize - 1)
+            }
+            _ => Err(self.unexpected("a variable name")),
+        }
+    }
+
+    fn set_bound(&mut self, col: usize, op: ConstraintType, value: f64) {
+        let column = &mut self.columns[col];
+        let value = value.clamp(-INFINITY, INFINITY);
+        if op != ConstraintType::Le {
+            column.lower = value;
+        }
+        if op != ConstraintType::Ge {
+            column.upper = value;
+        }
+    }
+
+    fn bound(&mut self) -> Result<(), ParseError> {
+        if let Token::Ident(_) = *self.peek() {
+            let col = self.variable()?;
+            if let Token::Ident(ref word) = *self.peek() {
+                if word.eq_ignore_ascii_case("free") {
+                    self.advance();
+                    self.columns[col].lower = -INFINITY;
+                    self.columns[col].upper = INFINITY;
+                    return Ok(());
+                }
+            }
+            let op = self.operator()?;
+            let value = self.number()?;
+            self.set_bound(col, op, value);
+            return Ok(());
+        }
+        let value = self.number()?;
+        let op = self.operator()?;
+        let col = self.variable()?;
+        let flipped = match op {
+            ConstraintType::Le => ConstraintType::Ge,
+            ConstraintType::Ge => ConstraintType::Le,
+            op => op,
+        };
+        self.set_bound(col, flipped, value);
+        if let Token::Op(op) = *self.peek() {
+            self.advance();
+            let value = self.number()?;
+            self.set_bound(col, op, value);
+        }
+        Ok(())
+    }
+
+    fn sos(&mut self, priority: c_int) -> Result<SOSData, ParseError> {
+        let name = match (self.peek_at(1), self.peek_at(3)) {
+            (&Token::Colon, &Token::DoubleColon) => self.label(),
+            _ => None,
+        };
+        let sostype = match (self.advance(), self.advance()) {
+            (Token::Ident(ref kind), Token::DoubleColon) if kind.eq_ignore_ascii_case("s1") => SOSType::Type1,
+            (Token::Ident(ref kind), Token::DoubleColon) if kind.eq_ignore_ascii_case("s2") => SOSType::Type2,
+            _ => {
+                self.pos -= 2;
+                return Err(self.unexpected("`S1::` or `S2::`"));
+            }
+        };
+        let (mut variables, mut weights) = (Vec::new(), Vec::new());
+        while let (&Token::Ident(_), &Token::Colon, &Token::Number(_)) =
+              (self.peek(), self.peek_at(1), self.peek_at(2)) {
+            variables.push(self.variable()? as c_int + 1);
+            self.advance();
+            weights.push(self.number()?);
+        }
+        Ok(SOSData { name: name.unwrap_or_else(|| format!("SOS{}", priority)), sostype, priority, variables, weights })
+    }
+
+    fn model(&mut self) -> Result<ModelData, ParseError> {
+        let maximize = match *self.peek() {
+            Token::Section(Section::Maximize) => true,
+            Token::Section(Section::Minimize) => false,
+            _ => return Err(self.unexpected("`Maximize` or `Minimize`")),
+        };
+        self.advance();
+        self.label();
+        let (objective, objective_constant) = self.expression()?;
+        if !self.at_section() {
+            return Err(self.unexpected("a section header"));
+        }
+
+        let mut rows = Vec::new();
+        let mut sos = Vec::new();
+        loop {
+            let section = match self.advance() {
+                Token::Section(Section::End) | Token::Eof => break,
+                Token::Section(section) => section,
+                _ => unreachable!(),
+            };
+            while !self.at_section() {
+                match section {
+                    Section::SubjectTo => rows.push(self.constraint()?),
+                    Section::Bounds => self.bound()?,
+                    Section::General | Section::Binary | Section::SemiContinuous => {
+                        let col = self.variable()?;
+                        let column = &mut self.columns[col];
+                        match section {
+                            Section::General => column.integer = true,
+                            Section::Binary => {
+                                column.integer = true;
+                                column.lower = 0.0;
+                                column.upper = 1.0;
+                            }
+                            _ => column.semicontinuous = true,
+                        }
+                    }
+                    Section::Sos => {
+                        let priority = sos.len() as c_int + 1;
+                        sos.push(self.sos(priority)?);
+                    }
+                    _ => return Err(self.unexpected("a section header")),
+                }
+            }
+        }
+
+        for (i, row) in rows.iter_mut().enumerate() {
+            if row.name.as_ref().map_or(false, |name| *name == format!("R{}", i + 1)) {
+                row.name = None;
+            }
+        }
+        let columns = self.columns.drain(..).enumerate().map(|(j, col)| ColumnData {
+            name: if col.name == format!("C{}", j + 1) { None } else { Some(col.name) },
+            lower: col.lower,
+            upper: col.upper,
+            integer: col.integer,
+            semicontinuous: col.semicontinuous,
+        }).collect();
+        Ok(ModelData {
+            name: String::new(),
+            maximize,
+            objective: objective.into_iter().filter(|&(_, v)| v != 0.0).collect(),
+            objective_constant,
+            infinity: INFINITY,
+            rows,
+            columns,
+            sos,
+        })
+    }
+}
+
+/// Parse a model in the CPLEX LP format.
+pub fn parse(src: &str) -> Result<ModelData, ParseError> {
+    let mut parser = Parser { tokens: tokenize(src)?, pos: 0, columns: Vec::new(), column_index: HashMap::new() };
+    parser.model()
+}
+
+fn number(value: f64, infinity: f64) -> String {
+    if value >= infinity {
+        "inf".to_string()
+    } else if value <= -infinity {
+        "-inf".to_string()
+    } else {
+        format_number(value)
+    }
+}
+
+fn write_terms(out: &mut dyn Write, names: &[String], coefficients: &[(c_int, f64)]) -> io::Result<()> {
+    for (i, &(col, value)) in coefficients.iter().enumerate() {
+        let sign = if value < 0.0 { "-" } else { "+" };
+        if i > 0 {
+            write!(out, " {} ", sign)?;
+        } else if value < 0.0 {
+            write!(out, "-")?;
+        }
+        if value.abs() != 1.0 {
+            write!(out, "{} ", format_number(value.abs()))?;
+        }
+        write!(out, "{}", names[col as usize - 1])?;
+    }
+    Ok(())
+}
+
+fn operator(kind: ConstraintType) -> &'static str {
+    match kind {
+        ConstraintType::Le => "<=",
+        ConstraintType::Eq => "=",
+        ConstraintType::Ge | ConstraintType::Free => ">=",
+    }
+}
+
+/// Write a model in the CPLEX LP format.
+///
+/// Rows and columns without a name are written with their default names `R{i}` and `C{j}`.
+///
+/// Fails with an `InvalidInput` error, before writing anything, if a name wouldn't be read back
+/// as written, because it has characters outside of names, like `x y` or `a:b`, starts with a
+/// digit or is a keyword, like `end` or `inf`.
+pub fn write(data: &ModelData, out: &mut dyn Write) -> io::Result<()> {
+    let inf = data.infinity;
+    let names: Vec<String> = data.columns.iter().enumerate()
+        .map(|(j, col)| col.name.clone().unwrap_or_else(|| format!("C{}", j + 1)))
+        .collect();
+    for name in names.iter().chain(data.rows.iter().filter_map(|row| row.name.as_ref())) {
+        check_name(name)?;
+    }
+    for set in &data.sos {
+        check_name(&set.name)?;
+    }
+
+    if !data.name.is_empty() {
+        writeln!(out, "\\ Problem name: {}", data.name)?;
+    }
+    writeln!(out, "{}", if data.maximize { "Maximize" } else { "Minimize" })?;
+    write!(out, " obj: ")?;
+    // Readers number columns in order of first appearance, so list every column in the objective
+    // when that order would differ from the model's.
+    let mut seen = HashSet::new();
+    let order: Vec<c_int> = data.objective.iter().chain(data.rows.iter().flat_map(|r| r.coefficients.iter()))
+        .map(|&(col, _)| col)
+        .filter(|&col| seen.insert(col))
+        .collect();
+    if order.len() == data.columns.len() && order.iter().enumerate().all(|(i, &col)| col as usize == i + 1) {
+        write_terms(out, &names, &data.objective)?;
+    } else {
+        let mut dense: Vec<(c_int, f64)> = (1..data.columns.len() as c_int + 1).map(|col| (col, 0.0)).collect();
+        for &(col, value) in &data.objective {
+            dense[col as usize - 1].1 = value;
+        }
+        write_terms(out, &names, &dense)?;
+    }
+    if data.objective_constant != 0.0 {
+        let sign = if data.objective_constant < 0.0 { "-" } else { "+" };
+        write!(out, " {} {}", sign, format_number(data.objective_constant.abs()))?;
+    }
+    writeln!(out)?;
+
+    writeln!(out, "Subject To")?;
+    for (i, row) in data.rows.iter().enumerate() {
+        match row.name {
+            Some(ref name) => write!(out, " {}: ", name)?,
+            None => write!(out, " R{}: ", i + 1)?,
+        }
+        let (kind, rhs) = match row.kind {
+            ConstraintType::Free => (ConstraintType::Ge, -inf),
+            kind => (kind, row.rhs),
+        };
+        match row.range {
+            Some(range) if range < inf && kind == ConstraintType::Le => {
+                write!(out, "{} <= ", number(rhs - range, inf))?
+            }
+            Some(range) if range < inf && kind == ConstraintType::Ge => {
+                write!(out, "{} >= ", number(rhs + range, inf))?
+            }
+            _ => {}
+        }
+        if row.coefficients.is_empty() && !names.is_empty() {
+            write!(out, "0 {}", names[0])?;
+        }
+        write_terms(out, &names, &row.coefficients)?;
+        writeln!(out, " {} {}", operator(kind), number(rhs, inf))?;
+    }
+
+    let binary = |col: &ColumnData| col.integer && col.lower == 0.0 && col.upper == 1.0;
+    let mut bounds = Vec::new();
+    for (col, name) in data.columns.iter().zip(&names) {
+        if binary(col) {
+            continue;
+        }
+        let (lower, upper) = (col.lower, col.upper);
+        if lower == upper {
+            bounds.push(format!(" {} = {}", name, number(upper, inf)));
+        } else if lower <= -inf && upper >= inf {
+            bounds.push(format!(" {} free", name));
+        } else if lower != 0.0 && upper < inf {
+            bounds.push(format!(" {} <= {} <= {}", number(lower, inf), name, number(upper, inf)));
+        } else if lower != 0.0 {
+            bounds.push(format!(" {} >= {}", name, number(lower, inf)));
+        } else if upper < inf {
+            bounds.push(format!(" {} <= {}", name, number(upper, inf)));
+        }
+    }
+    if !bounds.is_empty() {
+        writeln!(out, "Bounds")?;
+        for bound in bounds {
+            writeln!(out, "{}", bound)?;
+        }
+    }
+
+    for &header in &["General", "Binary", "Semi-Continuous"] {
+        let members: Vec<&String> = data.columns.iter().zip(&names)
+            .filter(|&(col, _)| match header {
+                "General" => col.integer && !binary(col),
+                "Binary" => binary(col),
+                _ => col.semicontinuous,
+            })
+            .map(|(_, name)| name).collect();
+        if !members.is_empty() {
+            writeln!(out, "{}", header)?;
+            for name in members {
+                writeln!(out, " {}", name)?;
+            }
+        }
+    }
+
+    if !data.sos.is_empty() {
+        writeln!(out, "SOS")?;
+        let mut sets: Vec<&SOSData> = data.sos.iter().collect();
+        sets.sort_by_key(|set| set.priority);
+        for set in sets {
+            write!(out, " {}: S{}::", set.name, set.sostype as c_int)?;
+            for (&col, &weight) in set.variables.iter().zip(&set.weights) {
+                write!(out, " {}:{}", names[col as usize - 1], format_number(weight))?;
+            }
+            writeln!(out)?;
+        }
+    }
+    writeln!(out, "End")
+}
+
+impl Problem {
+    /// Read a model in the CPLEX LP format.
+    ///
+    /// See the `cplex_lp` module for the details of the format.
+    pub fn read_cplex_lp(input: &mut dyn Read) -> Result<Problem, ParseError> {
+        let mut src = String::new();
+        input.read_to_string(&mut src)
+            .map_err(|e| ParseError::new(format!("could not read the model: {}", e), Span::default()))?;
+        parse(&src)?.to_problem()
+            .ok_or_else(|| ParseError::new("lpsolve rejected the model".to_string(), Span::default()))
+    }
+
+    /// Write the model in the CPLEX LP format.
+    ///
+    /// Returns `false` if a name can't be written, see `cplex_lp::write`.
+    pub fn write_cplex_lp(&self, out: &mut dyn Write) -> bool {
+        write(&ModelData::from_problem(self), out).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use std::io;
+
+    use {ConstraintType, ModelData, Problem, SOSType};
+    use lp_format;
+    use super::{parse, write};
+
+    fn example() -> Problem {
+        let mut lp = Problem::new(0, 4).unwrap();
+        lp.set_maxim();
+        assert!(lp.scatter_objective_function(&[143.0, 60.0, 1.0, -0.5], &[1, 2, 3, 4]));
+        assert!(lp.add_constraint_scatter(&[120.0, 210.0], &[1, 2], 15000.0, ConstraintType::Le));
+        assert!(lp.add_constraint_scatter(&[110.0, 30.0, 1.0], &[1, 2, 4], 4000.0, ConstraintType::Ge));
+        assert!(lp.add_constraint_scatter(&[1.0, 1.0, 1.0], &[1, 2, 3], 75.0, ConstraintType::Le));
+        assert!(lp.set_constraint_range(3, 10.0));
+        assert!(lp.add_constraint_scatter(&[1.0], &[4], 2.0, ConstraintType::Eq));
+        assert!(lp.set_row_name(1, &CString::new("land").unwrap()));
+        assert!(lp.set_col_name(2, &CString::new("barley").unwrap()));
+        assert!(lp.set_bounds(3, 0.0, 5.0));
+        assert!(lp.set_integer(3, true));
+        assert!(lp.set_bounds(4, -1e30, 1e30));
+        assert!(lp.set_rh(0, 3.0));
+        assert!(lp.add_sos_constraint(&CString::new("s1").unwrap(), SOSType::Type1, 1, &[1.0, 2.0], &[1, 2]));
+        lp
+    }
+
+    fn lp_text(lp: &Problem) -> String {
+        let mut out = Vec::new();
+        assert!(lp.write_lp(&mut out));
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let lp = example();
+        let mut out = Vec::new();
+        assert!(lp.write_cplex_lp(&mut out));
+        let copy = Problem::read_cplex_lp(&mut &out[..]).unwrap();
+        assert_eq!(lp_text(&copy), lp_text(&lp));
+    }
+
+    #[test]
+    fn free_row() {
+        let mut data = ModelData::from_problem(&example());
+        data.rows[1].kind = ConstraintType::Free;
+        data.rows[1].rhs = -1e30;
+        let mut out = Vec::new();
+        write(&data, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains(">= -inf"));
+        assert_eq!(parse(&text).unwrap(), data);
+        assert!(Problem::read_cplex_lp(&mut text.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn matches_lp_format() {
+        let cplex = "\\ A comment\n\
+                     Maximize\n obj: 3 x + 2y - z\n\
+                     Subject To\n c1: x + y <= 4\n c2: x + 3 y >= 6 \\ trailing\n\
+                     c3: -2 <= x - z <= 8\n y - 2 z = 1\n\
+                     Bounds\n x <= 40\n -inf <= z <= 10\n y free\n\
+                     Generals\n x\n\
+                     Binaries\n b\n\
+                     SOS\n s1: S2:: x:1 y:2 z:3\n\
+                     End\n";
+        let native = "max: 3 x + 2 y - z;\n\
+                      c1: x + y <= 4;\n c2: x + 3 y >= 6;\n c3: 8 >= x - z >= -2;\n R4: y - 2 z = 1;\n\
+                      x <= 40;\n -1e30 <= z <= 10;\n y >= -1e30;\n\
+                      int x;\n bin b;\n\
+                      sos\n s1: x:1, y:2, z:3 <= 2;\n";
+        let data = parse(cplex).unwrap();
+        assert_eq!(data, lp_format::parse(native).unwrap().to_model_data().unwrap());
+        assert_eq!((data.rows[2].kind, data.rows[2].rhs, data.rows[2].range), (ConstraintType::Le, 8.0, Some(10.0)));
+
+        let mut out = Vec::new();
+        super::write(&data, &mut out).unwrap();
+        assert_eq!(parse(&String::from_utf8(out).unwrap()).unwrap(), data);
+    }
+
+    #[test]
+    fn errors() {
+        let e = parse("Maximize\n obj: x\nSubject To\n c1: x + <= 4\nEnd").unwrap_err();
+        assert_eq!(e.to_string(), "line 4, column 10: expected a number or variable, found an operator");
+        let e = parse("Subject To\n c1: x <= 4\nEnd").unwrap_err();
+        assert_eq!((e.span.line, e.span.column), (1, 1));
+    }
+
+    #[test]
+    fn unwritable_names() {
+        let write = |data: &ModelData| super::write(data, &mut Vec::new());
+        let mut data = parse("Maximize\n obj: x + y\nSubject To\n c1: x + y <= 4\nEnd").unwrap();
+        for &name in &["end", "ST", "bin", "gen", "bounds", "semi", "inf", "x y", "a:b", "x+y", "x<1", "2x", ".5"] {
+            data.columns[0].name = Some(name.to_string());
+            let err = write(&data).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{}", name);
+            assert_eq!(err.to_string(), format!("`{}` can't be written as a CPLEX LP name", name));
+        }
+        data.columns[0].name = Some("x.1[a]".to_string());
+        data.rows[0].name = Some("subject".to_string());
+        assert!(write(&data).is_ok());
+        data.rows[0].name = Some("End".to_string());
+        assert!(write(&data).is_err());
+        data.rows[0].name = Some("c_1".to_string());
+
+        let mut out = Vec::new();
+        super::write(&data, &mut out).unwrap();
+        assert_eq!(parse(&String::from_utf8(out).unwrap()).unwrap(), data);
+        let mut lp = data.to_problem().unwrap();
+        assert!(lp.add_sos_constraint(&CString::new("sos").unwrap(), SOSType::Type1, 1, &[1.0, 2.0], &[1, 2]));
+        assert!(!lp.write_cplex_lp(&mut Vec::new()));
+    }
+}
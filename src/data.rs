@@ -0,0 +1,408 @@
+//! Owned snapshots of models and solutions.
+//!
+//! A `Problem` is a handle to memory owned by lpsolve, so it can't be stored or sent anywhere
+//! except as LP or MPS text. The types here hold the same information in plain Rust data. With
+//! the `serde` feature enabled they implement `Serialize` and `Deserialize`.
+//!
+//! Row and column indices follow the lpsolve convention: columns are numbered from 1, and row 0
+//! is the objective function.
+
+use std::ffi::CString;
+
+use libc::c_int;
+
+use {ConstraintType, Problem, SOSType, SolveStatus};
+
+/// A snapshot of a complete model.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ModelData {
+    /// The name of the model, empty if it has none.
+    pub name: String,
+    /// Whether the objective function is maximized.
+    pub maximize: bool,
+    /// The nonzero objective function coefficients as `(column, value)` pairs.
+    pub objective: Vec<(c_int, f64)>,
+    /// The constant term of the objective function.
+    pub objective_constant: f64,
+    /// The value the model uses for "infinite" bounds.
+    pub infinity: f64,
+    /// The constraints, in row order starting at row 1.
+    pub rows: Vec<RowData>,
+    /// The variables, in column order starting at column 1.
+    pub columns: Vec<ColumnData>,
+    /// The special ordered sets.
+    pub sos: Vec<SOSData>,
+}
+
+/// A single constraint of a `ModelData`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RowData {
+    /// The name of the row, `None` if it uses the default name.
+    pub name: Option<String>,
+    /// The nonzero coefficients as `(column, value)` pairs.
+    pub coefficients: Vec<(c_int, f64)>,
+    pub kind: ConstraintType,
+    pub rhs: f64,
+    /// The range of the constraint, see `Problem::set_constraint_range`.
+    pub range: Option<f64>,
+}
+
+/// A single variable of a `ModelData`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ColumnData {
+    /// The name of the column, `None` if it uses the default name.
+    pub name: Option<String>,
+    pub lower: f64,
+    pub upper: f64,
+    pub integer: bool,
+    pub semicontinuous: bool,
+}
+
+/// A special ordered set of a `ModelData`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SOSData {
+    pub name: String,
+    pub sostype: SOSType,
+    pub priority: c_int,
+    /// The member columns, in order of increasing weight.
+    pub variables: Vec<c_int>,
+    pub weights: Vec<f64>,
+}
+
+impl RowData {
+    /// The lowest and highest value the row may take, taking its type and range into account.
+    /// Missing limits are `-infinity` and `infinity`.
+    pub fn limits(&self, infinity: f64) -> (f64, f64) {
+        let range = self.range.unwrap_or(infinity);
+        match self.kind {
+            ConstraintType::Le => (if range < infinity { self.rhs - range } else { -infinity }, self.rhs),
+            ConstraintType::Ge => (self.rhs, if range < infinity { self.rhs + range } else { infinity }),
+            ConstraintType::Eq => (self.rhs, self.rhs),
+            ConstraintType::Free => (-infinity, infinity),
+        }
+    }
+}
+
+fn explicit_name(name: Option<String>, prefix: char, index: c_int) -> Option<String> {
+    name.and_then(|name| if name == format!("{}{}", prefix, index) { None } else { Some(name) })
+}
+
+fn sparse_row(problem: &Problem, row: c_int) -> Vec<(c_int, f64)> {
+    let cols = problem.num_cols() as usize;
+    let mut values = vec![0.0; cols];
+    let mut indices = vec![0; cols];
+    let len = problem.get_row_scatter(&mut values, &mut indices, row).unwrap_or(0);
+    indices[..len].iter().cloned().zip(values[..len].iter().cloned()).filter(|&(_, v)| v != 0.0).collect()
+}
+
+impl ModelData {
+    /// Take a snapshot of `problem`.
+    pub fn from_problem(problem: &Problem) -> ModelData {
+        let rows = (1..problem.num_rows() + 1).map(|row| {
+            let kind = problem.get_constraint_type(row).unwrap_or(ConstraintType::Free);
+            RowData {
+                name: explicit_name(problem.get_row_name(row), 'R', row),
+                coefficients: sparse_row(problem, row),
+                kind,
+                rhs: problem.get_rh(row).unwrap_or(0.0),
+                range: if kind == ConstraintType::Eq { None } else { problem.get_constraint_range(row) },
+            }
+        }).collect();
+        let columns = (1..problem.num_cols() + 1).map(|col| {
+            ColumnData {
+                name: explicit_name(problem.get_col_name(col), 'C', col),
+                lower: problem.get_lower_bound(col).unwrap_or(0.0),
+                upper: problem.get_upper_bound(col).unwrap_or(0.0),
+                integer: problem.is_integer(col).unwrap_or(false),
+                semicontinuous: problem.is_semicontinuous(col).unwrap_or(false),
+            }
+        }).collect();
+        ModelData {
+            name: problem.get_name(),
+            maximize: problem.is_maxim(),
+            objective: sparse_row(problem, 0),
+            objective_constant: problem.get_rh(0).unwrap_or(0.0),
+            infinity: problem.get_infinite(),
+            rows,
+            columns,
+            sos: problem.get_sos_constraints(),
+        }
+    }
+
+    /// Build a new `Problem` from this snapshot.
+    ///
+    /// Returns `None` if lpsolve rejects any part of the model, for example because a
+    /// coefficient refers to a column that doesn't exist or a name contains a NUL byte.
+    pub fn to_problem(&self) -> Option<Problem> {
+        let mut problem = Problem::new(0, self.columns.len() as c_int)?;
+        problem.set_infinite(self.infinity);
+        if !self.name.is_empty() {
+            let name = CString::new(self.name.as_str()).ok()?;
+            check!(problem.set_name(&name));
+        }
+        if self.maximize {
+            problem.set_maxim();
+        }
+
+        for (i, col) in self.columns.iter().enumerate() {
+            let idx = i as c_int + 1;
+            // Not `set_bounds`, which rejects crossed bounds that lpsolve otherwise allows.
+            check!(problem.set_lower_bound(idx, col.lower));
+            check!(problem.set_upper_bound(idx, col.upper));
+            check!(problem.set_integer(idx, col.integer));
+            check!(problem.set_semicontinuous(idx, col.semicontinuous));
+            if let Some(ref name) = col.name {
+                let name = CString::new(name.as_str()).ok()?;
+                check!(problem.set_col_name(idx, &name));
+            }
+        }
+
+        let (indices, coeffs): (Vec<c_int>, Vec<f64>) = self.objective.iter().cloned().unzip();
+        check!(problem.scatter_objective_function(&coeffs, &indices));
+        check!(problem.set_rh(0, self.objective_constant));
+
+        for (i, row) in self.rows.iter().enumerate() {
+            let idx = i as c_int + 1;
+            let (indices, coeffs): (Vec<c_int>, Vec<f64>) = row.coefficients.iter().cloned().unzip();
+            // lpsolve only takes `Free` from `set_constr_type`, not when adding the row.
+            if row.kind == ConstraintType::Free {
+                check!(problem.add_constraint_scatter(&coeffs, &indices, row.rhs, ConstraintType::Ge));
+                check!(problem.set_constraint_type(idx, ConstraintType::Free));
+            } else {
+                check!(problem.add_constraint_scatter(&coeffs, &indices, row.rhs, row.kind));
+            }
+            if let Some(range) = row.range {
+                check!(problem.set_constraint_range(idx, range));
+            }
+            if let Some(ref name) = row.name {
+                let name = CString::new(name.as_str()).ok()?;
+                check!(problem.set_row_name(idx, &name));
+            }
+        }
+
+        for sos in &self.sos {
+            let name = CString::new(sos.name.as_str()).ok()?;
+            check!(problem.add_sos_constraint(&name, sos.sostype, sos.priority, &sos.weights, &sos.variables));
+        }
+
+        Some(problem)
+    }
+}
+
+impl<'a> From<&'a Problem> for ModelData {
+    fn from(problem: &'a Problem) -> ModelData {
+        ModelData::from_problem(problem)
+    }
+}
+
+/// A snapshot of the results of the most recent `solve`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SolutionData {
+    pub status: SolveStatus,
+    pub objective: f64,
+    /// The value of each variable, in column order.
+    pub variables: Vec<f64>,
+    /// The value of each constraint, in row order.
+    pub constraints: Vec<f64>,
+    /// The dual value of each constraint, if available.
+    pub duals: Option<Vec<f64>>,
+    /// The reduced cost of each variable, if available.
+    pub reduced_costs: Option<Vec<f64>>,
+}
+
+impl SolutionData {
+    /// Take a snapshot of the most recent solution of `problem`. The status is `NotRun` if
+    /// lpsolve reports one that isn't the outcome of a solve.
+    pub fn from_problem(problem: &Problem) -> SolutionData {
+        let rows = problem.num_rows() as usize;
+        let cols = problem.num_cols() as usize;
+        let mut variables = vec![0.0; cols];
+        problem.get_solution_variables(&mut variables);
+        let mut constraints = vec![0.0; rows];
+        problem.get_solution_constraints(&mut constraints);
+        let mut duals = vec![0.0; rows + cols];
+        let (duals, reduced_costs) = if problem.get_dual_solution(&mut duals).is_some() {
+            let reduced_costs = duals.split_off(rows);
+            (Some(duals), Some(reduced_costs))
+        } else {
+            (None, None)
+        };
+        SolutionData {
+            status: problem.get_status().unwrap_or(SolveStatus::NotRun),
+            objective: problem.get_objective(),
+            variables,
+            constraints,
+            duals,
+            reduced_costs,
+        }
+    }
+}
+
+/// The sensitivity of the dual value of a constraint or variable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DualSensitivity {
+    pub dual: f64,
+    /// Lower limit of the range over which `dual` is valid.
+    pub from: f64,
+    /// Upper limit of the range over which `dual` is valid.
+    pub till: f64,
+}
+
+/// The sensitivity of an objective function coefficient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ObjectiveSensitivity {
+    /// Lower limit of the coefficient before the solution changes.
+    pub from: f64,
+    /// Upper limit of the coefficient before the solution changes.
+    pub till: f64,
+    /// The objective function value when the coefficient is at its lower limit.
+    pub from_value: f64,
+}
+
+/// A snapshot of the sensitivity analysis of the most recent `solve`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SensitivityData {
+    /// One entry per constraint, in row order.
+    pub constraints: Vec<DualSensitivity>,
+    /// One entry per variable, in column order. The dual is the reduced cost.
+    pub variables: Vec<DualSensitivity>,
+    /// One entry per variable, in column order.
+    pub objective: Vec<ObjectiveSensitivity>,
+}
+
+impl SensitivityData {
+    /// Take a snapshot of the sensitivity analysis of `problem`.
+    ///
+    /// Returns `None` if lpsolve has no sensitivity information, for example because the model
+    /// wasn't solved.
+    pub fn from_problem(problem: &Problem) -> Option<SensitivityData> {
+        let rows = problem.num_rows() as usize;
+        let cols = problem.num_cols() as usize;
+        let (mut duals, mut from, mut till) = (vec![0.0; rows + cols], vec![0.0; rows + cols], vec![0.0; rows + cols]);
+        check!(problem.get_sensitivity_rhs(&mut duals, &mut from, &mut till));
+        let (mut objfrom, mut objtill, mut objfromvalue) = (vec![0.0; cols], vec![0.0; cols], vec![0.0; cols]);
+        check!(problem.get_sensitivity_obj(&mut objfrom, &mut objtill, &mut objfromvalue));
+
+        let mut duals: Vec<_> = (0..rows + cols).map(|i| DualSensitivity { dual: duals[i], from: from[i], till: till[i] }).collect();
+        let variables = duals.split_off(rows);
+        Some(SensitivityData {
+            constraints: duals,
+            variables,
+            objective: (0..cols).map(|i| ObjectiveSensitivity { from: objfrom[i], till: objtill[i], from_value: objfromvalue[i] }).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use {ConstraintType, Problem, SOSType, SolveStatus};
+    use super::*;
+
+    fn example() -> Problem {
+        let mut lp = Problem::new(0, 3).unwrap();
+        lp.set_maxim();
+        assert!(lp.scatter_objective_function(&[143.0, 60.0, 1.0], &[1, 2, 3]));
+        assert!(lp.add_constraint_scatter(&[120.0, 210.0], &[1, 2], 15000.0, ConstraintType::Le));
+        assert!(lp.add_constraint_scatter(&[110.0, 30.0], &[1, 2], 4000.0, ConstraintType::Le));
+        assert!(lp.add_constraint_scatter(&[1.0, 1.0, 1.0], &[1, 2, 3], 75.0, ConstraintType::Le));
+        assert!(lp.set_constraint_range(3, 10.0));
+        assert!(lp.set_row_name(1, &CString::new("land").unwrap()));
+        assert!(lp.set_col_name(2, &CString::new("barley").unwrap()));
+        assert!(lp.set_bounds(3, 0.0, 5.0));
+        assert!(lp.set_integer(3, true));
+        assert!(lp.add_sos_constraint(&CString::new("s1").unwrap(), SOSType::Type1, 1, &[1.0, 2.0], &[1, 2]));
+        lp
+    }
+
+    fn lp_text(lp: &Problem) -> String {
+        let mut out = Vec::new();
+        assert!(lp.write_lp(&mut out));
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn snapshot() {
+        let data = ModelData::from_problem(&example());
+        assert!(data.maximize);
+        assert_eq!(data.objective, vec![(1, 143.0), (2, 60.0), (3, 1.0)]);
+        assert_eq!(data.rows.len(), 3);
+        assert_eq!(data.rows[0].name, Some("land".to_string()));
+        assert_eq!(data.rows[1].name, None);
+        assert_eq!(data.rows[2].range, Some(10.0));
+        assert_eq!(data.columns[1].name, Some("barley".to_string()));
+        assert!(data.columns[2].integer);
+        assert_eq!(data.columns[2].upper, 5.0);
+        assert_eq!(data.sos.len(), 1);
+        assert_eq!(data.sos[0].variables, vec![1, 2]);
+    }
+
+    #[test]
+    fn round_trip() {
+        let lp = example();
+        let copy = ModelData::from_problem(&lp).to_problem().unwrap();
+        assert_eq!(lp_text(&lp), lp_text(&copy));
+    }
+
+    #[test]
+    fn crossed_bounds_round_trip() {
+        let mut lp = example();
+        assert!(lp.set_lower_bound(1, 5.0));
+        assert!(lp.set_upper_bound(1, 2.0));
+        let data = ModelData::from_problem(&lp);
+        assert_eq!((data.columns[0].lower, data.columns[0].upper), (5.0, 2.0));
+        assert_eq!(ModelData::from_problem(&data.to_problem().unwrap()), data);
+    }
+
+    #[test]
+    fn free_row() {
+        let mut lp = example();
+        let mut data = ModelData::from_problem(&lp);
+        data.rows[0].kind = ConstraintType::Free;
+        // lpsolve keeps a free row as `<=` an infinite right hand side.
+        assert!(lp.set_constraint_type(1, ConstraintType::Free));
+        assert_eq!(lp_text(&data.to_problem().unwrap()), lp_text(&lp));
+    }
+
+    #[test]
+    fn solution() {
+        let mut lp = example();
+        assert_eq!(lp.solve(), SolveStatus::Optimal);
+        let solution = SolutionData::from_problem(&lp);
+        assert_eq!(solution.status, SolveStatus::Optimal);
+        assert_eq!(solution.variables.len(), 3);
+        assert_eq!(solution.constraints.len(), 3);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        use {bincode, serde_json};
+
+        let data = ModelData::from_problem(&example());
+        let json = serde_json::to_string(&data).unwrap();
+        assert_eq!(serde_json::from_str::<ModelData>(&json).unwrap(), data);
+        let bytes = bincode::serialize(&data).unwrap();
+        assert_eq!(bincode::deserialize::<ModelData>(&bytes).unwrap(), data);
+
+        let mut relaxed = data.clone();
+        relaxed.sos.clear();
+        relaxed.columns[2].integer = false;
+        let mut lp = relaxed.to_problem().unwrap();
+        assert_eq!(lp.solve(), SolveStatus::Optimal);
+        let solution = SolutionData::from_problem(&lp);
+        let json = serde_json::to_string(&solution).unwrap();
+        assert_eq!(serde_json::from_str::<SolutionData>(&json).unwrap(), solution);
+        let sensitivity = SensitivityData::from_problem(&lp).unwrap();
+        let json = serde_json::to_string(&sensitivity).unwrap();
+        assert_eq!(serde_json::from_str::<SensitivityData>(&json).unwrap(), sensitivity);
+    }
+}
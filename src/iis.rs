@@ -0,0 +1,150 @@
+//! Irreducible infeasible subsystems.
+//!
+//! An infeasible model usually has a small core of constraints that can't hold together, however
+//! large the model is. `Problem::find_iis` isolates one: a set of rows and bounds that is
+//! infeasible, but becomes feasible as soon as any one of them is dropped.
+
+use std::fmt;
+
+use libc::c_int;
+
+use {ConstraintType, Problem, SolveStatus};
+
+/// A row that is part of an irreducible infeasible subsystem.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IisRow {
+    pub row: c_int,
+    pub name: String,
+}
+
+/// A bound that is part of an irreducible infeasible subsystem.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IisBound {
+    pub col: c_int,
+    /// The name of the column.
+    pub name: String,
+    /// Whether this is the upper bound, rather than the lower.
+    pub upper: bool,
+    pub value: f64,
+}
+
+/// A set of rows and bounds that is infeasible, but feasible without any one of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Iis {
+    /// The rows, in row order.
+    pub rows: Vec<IisRow>,
+    /// The bounds, in column order.
+    pub bounds: Vec<IisBound>,
+}
+
+impl fmt::Display for Iis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in &self.rows {
+            writeln!(f, "row {}", row.name)?;
+        }
+        for bound in &self.bounds {
+            writeln!(f, "bound {} {} {}", bound.name, if bound.upper { "<=" } else { ">=" }, bound.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether `problem` is still infeasible with the change `relax` makes to a copy of it, in which
+/// case the copy replaces it.
+fn drop_if_infeasible<F: FnOnce(&mut Problem) -> bool>(problem: &mut Problem, relax: F) -> bool {
+    let mut trial = problem.clone();
+    if relax(&mut trial) && trial.solve() == SolveStatus::Infeasible {
+        *problem = trial;
+        true
+    } else {
+        false
+    }
+}
+
+impl Problem {
+    /// Finds an irreducible infeasible subsystem: a set of rows and finite bounds that can't
+    /// hold together, but can once any one of them is dropped. Returns `None` if the problem
+    /// isn't infeasible.
+    ///
+    /// This is a deletion filter. Each row in turn is made free and each bound infinite, on a
+    /// copy of the problem, and if the copy is still infeasible the relaxation is kept, otherwise
+    /// the row or bound is part of the subsystem. That takes a solve per row and bound, so it
+    /// is slow on large problems. Integrality, semicontinuity and special ordered sets are never
+    /// relaxed, so they may be what makes the subsystem infeasible. The problem itself is left
+    /// unchanged.
+    pub fn find_iis(&self) -> Option<Iis> {
+        let mut problem = self.clone();
+        // Without an objective a relaxation can't turn out unbounded instead of feasible.
+        check!(problem.scatter_objective_function(&[], &[]));
+        if problem.solve() != SolveStatus::Infeasible {
+            return None;
+        }
+
+        let mut rows = Vec::new();
+        for row in 1..=problem.num_rows() {
+            if !drop_if_infeasible(&mut problem, |p| p.set_constraint_type(row, ConstraintType::Free)) {
+                rows.push(IisRow { row, name: self.get_row_name(row)? });
+            }
+        }
+
+        let infinity = problem.get_infinite();
+        let mut bounds = Vec::new();
+        for col in 1..=problem.num_cols() {
+            for &upper in &[false, true] {
+                let value = if upper { problem.get_upper_bound(col)? } else { problem.get_lower_bound(col)? };
+                if value.abs() >= infinity {
+                    continue;
+                }
+                let dropped = drop_if_infeasible(&mut problem, |p| {
+                    if upper { p.set_upper_bound(col, infinity) } else { p.set_lower_bound(col, -infinity) }
+                });
+                if !dropped {
+                    bounds.push(IisBound { col, name: self.get_col_name(col)?, upper, value });
+                }
+            }
+        }
+        Some(Iis { rows, bounds })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lp_format;
+
+    #[test]
+    fn conflicting_rows() {
+        let problem = lp_format::parse("
+            max: x + y;
+            supply: x + y <= 10;
+            demand: x + y >= 12;
+            spread: x - y <= 100;
+            x <= 4;
+            y >= 1;
+        ").unwrap().to_problem().unwrap();
+        let iis = problem.find_iis().unwrap();
+        let rows: Vec<_> = iis.rows.iter().map(|row| row.name.as_str()).collect();
+        assert_eq!(rows, ["supply", "demand"]);
+        assert!(iis.bounds.is_empty());
+        assert_eq!(iis.to_string(), "row supply\nrow demand\n");
+    }
+
+    #[test]
+    fn rows_and_bounds() {
+        let mut problem = lp_format::parse("
+            min: x;
+            demand: x + y >= 12;
+            spread: x - y <= 100;
+            x <= 4;
+            y <= 5;
+        ").unwrap().to_problem().unwrap();
+        let iis = problem.find_iis().unwrap();
+        assert_eq!(iis.rows.len(), 1);
+        assert_eq!((iis.rows[0].row, iis.rows[0].name.as_str()), (1, "demand"));
+        let bounds: Vec<_> = iis.bounds.iter().map(|b| (b.name.as_str(), b.upper, b.value)).collect();
+        assert_eq!(bounds, [("x", true, 4.0), ("y", true, 5.0)]);
+
+        assert!(problem.set_upper_bound(2, 8.0));
+        assert_eq!(problem.find_iis(), None);
+        assert_eq!(problem.get_constraint_type(1), Some(::ConstraintType::Ge));
+    }
+}
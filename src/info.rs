@@ -0,0 +1,93 @@
+//! The version and build configuration of the linked lp_solve.
+//!
+//! These are mostly useful in bug reports and for checking at runtime that a system lp_solve is
+//! the one expected.
+
+use std::fmt;
+
+use libc::c_int;
+
+/// Returns the version of the linked lp_solve as `(major, minor, release, build)`.
+///
+/// With the `system` feature or `LPSOLVE_LIB_DIR` this is the library found at link time, which
+/// may differ from the bundled 5.5.2.x.
+pub fn version() -> (c_int, c_int, c_int, c_int) {
+    let (mut major, mut minor, mut release, mut build) = (0, 0, 0, 0);
+    unsafe { ::lp::lp_solve_version(&mut major, &mut minor, &mut release, &mut build) };
+    (major, minor, release, build)
+}
+
+/// Where the linked lp_solve came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Linkage {
+    /// The copy bundled with `lpsolve-sys`, built from source.
+    Bundled,
+    /// A library found through `LPSOLVE_LIB_DIR` or pkg-config.
+    System,
+}
+
+/// How lp_solve was built and linked, as returned by `build_info`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// Where lp_solve came from.
+    pub linkage: Linkage,
+    /// Whether it is linked statically.
+    pub static_link: bool,
+    /// The preprocessor definitions the bundled copy was compiled with, as `(name, value)`. Empty
+    /// for a system library, whose configuration isn't known.
+    pub defines: &'static [(&'static str, Option<&'static str>)],
+}
+
+impl BuildInfo {
+    /// Looks up a definition by name. Returns `Some(None)` if it is defined without a value.
+    pub fn define(&self, name: &str) -> Option<Option<&'static str>> {
+        self.defines.iter().find(|&&(n, _)| n == name).map(|&(_, value)| value)
+    }
+}
+
+impl fmt::Display for BuildInfo {
+    /// Formats as e.g. `bundled, static, INVERSE_ACTIVE=INVERSE_LUSOL PARSER_LP`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let linkage = match self.linkage {
+            Linkage::Bundled => "bundled",
+            Linkage::System => "system",
+        };
+        write!(f, "{}, {}", linkage, if self.static_link { "static" } else { "dynamic" })?;
+        for (i, &(name, value)) in self.defines.iter().enumerate() {
+            f.write_str(if i == 0 { ", " } else { " " })?;
+            match value {
+                Some(value) => write!(f, "{}={}", name, value)?,
+                None => f.write_str(name)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns how the linked lp_solve was built, as recorded by the `lpsolve-sys` build script.
+pub fn build_info() -> BuildInfo {
+    BuildInfo {
+        linkage: if ::lp::BUILD_LINKAGE == "bundled" { Linkage::Bundled } else { Linkage::System },
+        static_link: ::lp::BUILD_STATIC,
+        defines: ::lp::BUILD_DEFINES,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version_and_build_info() {
+        let info = build_info();
+        let (major, minor, _, _) = version();
+        assert_eq!((major, minor), (5, 5));
+        if info.linkage == Linkage::Bundled {
+            assert!(info.static_link);
+            assert_eq!(info.define("INVERSE_ACTIVE"), Some(Some("INVERSE_LUSOL")));
+            assert_eq!(info.define("PARSER_LP"), Some(None));
+            assert_eq!(info.define("NOT_DEFINED"), None);
+            assert!(info.to_string().starts_with("bundled, static, INVERSE_ACTIVE=INVERSE_LUSOL "));
+        }
+    }
+}
@@ -32,6 +32,47 @@
 //!
 //! This is not fundamental, merge requests welcome!
 //!
+//! # Serialization
+//!
+//! A `Problem` can be converted to and from a `ModelData` snapshot, and the results of a solve
+//! can be captured in `SolutionData` and `SensitivityData`. With the `serde` feature enabled,
+//! these implement `Serialize` and `Deserialize`.
+//!
+//! # LP format
+//!
+//! The `lp_format` module reads and writes the lpsolve lp-format in Rust, independent of the
+//! bundled C parser, and reports errors with their location in the source text. The `cplex_lp`
+//! module does the same for the CPLEX LP format, through `Problem::read_cplex_lp` and
+//! `Problem::write_cplex_lp`. With the `mathprog` feature enabled, the `mathprog` module imports
+//! models written in a subset of GNU MathProg, and with the `osil` feature the `osil` module reads
+//! and writes OSiL XML instances.
+//!
+//! # Modeling
+//!
+//! The `modeling` module builds models from `Variable`s and `LinearExpr`s, as in
+//! `model.add_constraint((x + y).le(10.0))`, instead of rows of coefficients.
+//!
+//! # Diagnostics
+//!
+//! When a problem is infeasible, `Problem::find_iis` narrows it down to an irreducible infeasible
+//! subsystem: a set of rows and bounds that can't all hold, but can once any one is dropped.
+//! `Problem::feasibility_relaxation` instead finds the solution that violates a chosen set of rows
+//! and bounds the least, and reports by how much it violates each. `Problem::certificate` proves
+//! an unbounded or infeasible status with a ray or Farkas multipliers, which `Certificate::check`
+//! verifies.
+//!
+//! `Problem::verify` checks any solution, whether it comes from lpsolve or elsewhere, against the
+//! rows, bounds, integrality, semicontinuity and special ordered sets of the model, and reports
+//! every requirement it fails with the size of the violation. `Problem::statistics` counts the
+//! rows and columns of a model by type and reports the ranges of its coefficients, along with
+//! empty, singleton and duplicate rows and free and fixed columns.
+//!
+//! # Build configuration
+//!
+//! `version` reports the version of the linked lp_solve, and `build_info` whether it is the
+//! bundled copy or a system library, along with the definitions the bundled copy was compiled
+//! with.
+//!
 //! # Stability
 //!
 //! `lpsolve-sys` is versioned separately from this wrapper. This wrapper is provisionally
@@ -51,14 +92,22 @@
 extern crate lpsolve_sys as lp;
 extern crate libc;
 #[macro_use] extern crate bitflags;
+#[cfg(feature = "serde")]
+#[macro_use] extern crate serde;
+#[cfg(feature = "osil")]
+extern crate roxmltree;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+#[cfg(all(test, feature = "serde"))]
+extern crate bincode;
 
-use std::mem::transmute;
 use std::io::Write;
 use std::ffi::CStr;
 use std::ops::Deref;
 
 #[repr(C)]
-#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Verbosity {
     Neutral = 0,
     Critical = 1,
@@ -70,7 +119,8 @@ pub enum Verbosity {
 }
 
 #[repr(C)]
-#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ConstraintType {
     Le = 1,
     Eq = 3,
@@ -79,28 +129,32 @@ pub enum ConstraintType {
 }
 
 #[repr(C)]
-#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SOSType {
     Type1 = 1,
     Type2 = 2,
 }
 
 #[repr(C)]
-#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum VarType {
     Binary = 1,
     Float = 0,
 }
 
 #[repr(u8)]
-#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BoundsMode {
     Restrictive = 1,
     None = 0,
 }
 
 #[repr(C)]
-#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SolveStatus {
     OutOfMemory = -2,
     NotRun = -1,
@@ -119,17 +173,59 @@ pub enum SolveStatus {
     NoFeasibleFound = 13,
 }
 
-bitflags! {
-    pub flags MPSOptions: ::libc::c_int {
-        const CRITICAL = 1,
-        const SEVERE = 2,
-        const IMPORTANT = 3,
-        const NORMAL = 4,
-        const DETAILED = 5,
-        const FULL = 6,
-        const FREE = 8,
-        const IBM = 16,
-        const NEGOBJCONST = 32,
+impl SolveStatus {
+    fn from_raw(status: libc::c_int) -> SolveStatus {
+        SolveStatus::try_from_raw(status).unwrap_or_else(|| panic!("unknown solve status {}", status))
+    }
+
+    fn try_from_raw(status: libc::c_int) -> Option<SolveStatus> {
+        use SolveStatus::*;
+        Some(match status {
+            -2 => OutOfMemory,
+            -1 => NotRun,
+            0 => Optimal,
+            1 => Suboptimal,
+            2 => Infeasible,
+            3 => Unbounded,
+            4 => Degenerate,
+            5 => NumericalFailure,
+            6 => UserAbort,
+            7 => Timeout,
+            9 => Presolved,
+            10 => ProcFail,
+            11 => ProcBreak,
+            12 => FeasibleFound,
+            13 => NoFeasibleFound,
+            _ => return None,
+        })
+    }
+}
+
+// bitflags 0.5 expands to `try!` and to a `cfg` that current compilers warn about. It stays
+// at 0.5 because later versions change the `MPSOptions` API.
+#[allow(deprecated, unexpected_cfgs)]
+mod mps_options {
+    bitflags! {
+        pub flags MPSOptions: ::libc::c_int {
+            const CRITICAL = 1,
+            const SEVERE = 2,
+            const IMPORTANT = 3,
+            const NORMAL = 4,
+            const DETAILED = 5,
+            const FULL = 6,
+            const FREE = 8,
+            const IBM = 16,
+            const NEGOBJCONST = 32,
+        }
+    }
+}
+pub use mps_options::*;
+
+unsafe fn from_cstr(ptr: *const libc::c_char) -> Option<String> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
     }
 }
 
@@ -142,21 +238,39 @@ macro_rules! cptr {
     ($e:expr) => { if $e.is_null() { None } else { Some(Problem { lprec: $e }) } }
 }
 
-#[cfg(not(windows))]
-unsafe extern "C" fn write_modeldata(val: *mut libc::c_void, buf: *mut libc::c_char) -> libc::c_int {
-    let val = transmute::<_, &mut &mut Write>(val);
-    let buf = CStr::from_ptr(buf);
-    match val.write(buf.to_bytes()) {
-        Ok(_) => 0,
-        Err(_) => 1,
-    }
+macro_rules! check {
+    ($e:expr) => { if !$e { return None } }
 }
 
-#[cfg(windows)]
-unsafe extern "stdcall" fn write_modeldata(val: *mut libc::c_void, buf: *mut libc::c_char) -> libc::c_int {
-    let val = transmute::<_, &mut &mut Write>(val);
+pub use data::{ModelData, RowData, ColumnData, SOSData, SolutionData, SensitivityData, DualSensitivity,
+               ObjectiveSensitivity};
+
+pub use certificate::Certificate;
+pub use iis::{Iis, IisRow, IisBound};
+pub use info::{version, build_info, BuildInfo, Linkage};
+pub use relaxation::{Relaxable, Relaxation, Violation};
+pub use statistics::Statistics;
+pub use verify::{Failure, Requirement, VerificationReport};
+
+mod certificate;
+mod data;
+mod iis;
+mod info;
+mod relaxation;
+mod statistics;
+mod verify;
+pub mod lp_format;
+pub mod cplex_lp;
+pub mod modeling;
+#[cfg(feature = "mathprog")]
+pub mod mathprog;
+#[cfg(feature = "osil")]
+pub mod osil;
+
+unsafe extern "system" fn write_modeldata(val: *mut libc::c_void, buf: *mut libc::c_char) -> libc::c_int {
+    let val = &mut *val.cast::<&mut dyn Write>();
     let buf = CStr::from_ptr(buf);
-    match val.write(buf.to_bytes()) {
+    match val.write_all(buf.to_bytes()) {
         Ok(_) => 0,
         Err(_) => 1,
     }
@@ -178,34 +292,34 @@ impl Problem {
 
     /// Read an mps-format model from `path` using the "free" formatting.
     pub fn read_freemps<P: Deref<Target=CStr>>(path: &P, options: MPSOptions) -> Option<Problem> {
-        let ptr = unsafe { lp::read_freeMPS(path.as_ptr() as *mut _, options.bits) };
+        let ptr = unsafe { lp::read_freeMPS(path.as_ptr() as *mut _, options.bits()) };
         cptr!(ptr)
     }
 
     /// Read an mps-format model from `path` using the fixed formatting.
     pub fn read_fixedmps<P: Deref<Target=CStr>>(path: &P, options: MPSOptions) -> Option<Problem> {
-        let ptr = unsafe { lp::read_MPS(path.as_ptr() as *mut _, options.bits) };
+        let ptr = unsafe { lp::read_MPS(path.as_ptr() as *mut _, options.bits()) };
         cptr!(ptr)
     }
 
     /// Write an lp-format model into `out`.
     ///
     /// If there are any errors writing to `out`, `false` will be returned. Otherwise, `true`.
-    pub fn write_lp(&self, out: &mut Write) -> bool {
-        1 == unsafe { lp::write_lpex(self.lprec, transmute::<_, *mut libc::c_void>(&out), write_modeldata) }
+    pub fn write_lp(&self, mut out: &mut dyn Write) -> bool {
+        1 == unsafe { lp::write_lpex(self.lprec, &mut out as *mut _ as *mut libc::c_void, Some(write_modeldata)) }
     }
 
     /// Write an mps-format model into `out` using the fixed formatting.
     ///
     /// If there are any errors writing to `out`, `false` will be returned. Otherwise, `true`.
-    pub fn write_fixedmps(&self, out: &mut Write) -> bool {
+    pub fn write_fixedmps(&self, out: &mut dyn Write) -> bool {
         self.write_mps(out, 1)
     }
 
     /// Write an mps-format model into `out` using the "free" formatting.
     ///
     /// If there are any errors writing to `out`, `false` will be returned. Otherwise, `true`.
-    pub fn write_freemps(&self, out: &mut Write) -> bool {
+    pub fn write_freemps(&self, out: &mut dyn Write) -> bool {
         self.write_mps(out, 2)
     }
 
@@ -214,9 +328,9 @@ impl Problem {
     /// If there are any errors writing to `out`, `false` will be returned. Otherwise, `true`.
     ///
     /// `formatting` must be 1 for fixed or 2 for free.
-    pub fn write_mps(&self, out: &mut Write, formatting: libc::c_int) -> bool {
+    pub fn write_mps(&self, mut out: &mut dyn Write, formatting: libc::c_int) -> bool {
         debug_assert!(formatting == 1 || formatting == 2);
-        1 == unsafe { lp::MPS_writefileex(self.lprec, formatting, transmute::<_, *mut libc::c_void>(&out), write_modeldata) }
+        1 == unsafe { lp::MPS_writefileex(self.lprec, formatting, &mut out as *mut _ as *mut libc::c_void, Some(write_modeldata)) }
     }
 
     /// Reserve enough memory for `rows` and `cols`.
@@ -252,16 +366,34 @@ impl Problem {
 
     /// Read a column from the model.
     pub fn get_column(&self, values: &mut [f64], column: libc::c_int) -> bool {
-        assert!(values.len() >= self.num_rows() as usize + 1);
+        assert!(values.len() > self.num_rows() as usize);
         1 == unsafe { lp::get_column(self.lprec, column, values.as_mut_ptr()) }
     }
 
     /// Read a row from the model.
     pub fn get_row(&self, values: &mut [f64], row: libc::c_int) -> bool {
-        assert!(values.len() >= self.num_cols() as usize + 1);
+        assert!(values.len() > self.num_cols() as usize);
         1 == unsafe { lp::get_row(self.lprec, row, values.as_mut_ptr()) }
     }
 
+    /// Read the nonzero entries of a row from the model.
+    ///
+    /// The value in `values[i]` belongs to column `indices[i]`. Returns the number of nonzero
+    /// entries written, or `None` if `row` is out of bounds.
+    ///
+    /// Asserts that `values` and `indices` have at least as many elements as the model has
+    /// columns.
+    pub fn get_row_scatter(&self, values: &mut [f64], indices: &mut [libc::c_int], row: libc::c_int) -> Option<usize> {
+        assert!(values.len() >= self.num_cols() as usize);
+        assert!(indices.len() >= self.num_cols() as usize);
+        let res = unsafe { lp::get_rowex(self.lprec, row, values.as_mut_ptr(), indices.as_mut_ptr()) };
+        if res < 0 {
+            None
+        } else {
+            Some(res as usize)
+        }
+    }
+
     /// Sets the verbosity of the output.
     pub fn set_verbose(&mut self, verbosity: Verbosity) {
         unsafe { lp::set_verbose(self.lprec, verbosity as libc::c_int) }
@@ -277,6 +409,11 @@ impl Problem {
         unsafe { lp::set_minim(self.lprec) }
     }
 
+    /// Returns `true` if the objective is to maximize R0.
+    pub fn is_maxim(&self) -> bool {
+        1 == unsafe { lp::is_maxim(self.lprec) }
+    }
+
     /// Gets the value of the objective function.
     pub fn get_objective(&self) -> f64 {
         unsafe { lp::get_objective(self.lprec) }
@@ -291,10 +428,25 @@ impl Problem {
     ///
     /// Asserts that `coeffs` has at least as many elements as the underlying model.
     pub fn add_constraint(&mut self, coeffs: &[f64], target: f64, kind: ConstraintType) -> bool {
-        assert!(coeffs.len() >= self.num_cols() as usize + 1);
+        assert!(coeffs.len() > self.num_cols() as usize);
         1 == unsafe { lp::add_constraint(self.lprec, coeffs.as_ptr() as *mut _, kind as libc::c_int, target) }
     }
 
+    /// Add a constraint to the model, scattering `coeffs` by `indices`.
+    ///
+    /// The constraint is that `coeffs[i] * vars[indices[i]] OP target`, summed over `i`, where
+    /// `OP` is specified by `kind`. Columns not mentioned in `indices` get a zero coefficient.
+    ///
+    /// The length used is the max of the lengths of `coeffs` and `indices`. There is a
+    /// debug_assert that these are equal.
+    pub fn add_constraint_scatter(&mut self, coeffs: &[f64], indices: &[libc::c_int], target: f64,
+                                  kind: ConstraintType) -> bool {
+        let len = std::cmp::max(coeffs.len(), indices.len());
+        debug_assert!(coeffs.len() == indices.len());
+        1 == unsafe { lp::add_constraintex(self.lprec, len as libc::c_int, coeffs.as_ptr() as *mut _,
+                                           indices.as_ptr() as *mut _, kind as libc::c_int, target) }
+    }
+
     /// Add a [Special Ordered Set](http://lpsolve.sourceforge.net/5.5/SOS.htm) constraint.
     ///
     /// The `weights` are scattered by `variables`, that is, `weights[i]` will be specified for
@@ -310,6 +462,49 @@ impl Problem {
                              len as libc::c_int, variables.as_ptr() as *mut _, weights.as_ptr() as *mut _) }
     }
 
+    /// The number of special ordered sets.
+    pub fn sos_count(&self) -> libc::c_int {
+        self.sos_records().len() as libc::c_int
+    }
+
+    /// Read out the special ordered sets, in the order lpsolve keeps them, which is by priority.
+    pub fn get_sos_constraints(&self) -> Vec<SOSData> {
+        let mut sets = Vec::new();
+        for &rec in self.sos_records() {
+            unsafe {
+                let sostype = match (*rec).type_.abs() {
+                    1 => SOSType::Type1,
+                    2 => SOSType::Type2,
+                    _ => continue,
+                };
+                let size = (*rec).size as usize;
+                sets.push(SOSData {
+                    name: from_cstr((*rec).name).unwrap_or_default(),
+                    sostype,
+                    priority: (*rec).priority,
+                    variables: std::slice::from_raw_parts((*rec).members.offset(1), size).to_vec(),
+                    weights: std::slice::from_raw_parts((*rec).weights.offset(1), size).to_vec(),
+                });
+            }
+        }
+        sets
+    }
+
+    /// lpsolve has no function to read special ordered sets back, so they are read from its
+    /// `SOSgroup` directly. The `layout-test` feature of `lpsolve-sys` checks the `lprec`,
+    /// `SOSgroup` and `SOSrec` fields used here against the bundled headers, so a system lpsolve
+    /// has to be built from the same 5.5 headers.
+    fn sos_records(&self) -> &[*mut lp::SOSrec] {
+        unsafe {
+            let group = (*self.lprec).SOS;
+            if group.is_null() {
+                &[]
+            } else {
+                std::slice::from_raw_parts((*group).sos_list, (*group).sos_count as usize)
+            }
+        }
+    }
+
     /// Delete a column from the model.
     ///
     /// The other columns are shifted leftward. `col` cannot be 0, as that column represents the
@@ -361,6 +556,34 @@ impl Problem {
         1 == unsafe { lp::set_bounds(self.lprec, col, lower, upper) }
     }
 
+    /// Set the lower bound of a variable.
+    pub fn set_lower_bound(&mut self, col: libc::c_int, lower: f64) -> bool {
+        1 == unsafe { lp::set_lowbo(self.lprec, col, lower) }
+    }
+
+    /// Set the upper bound of a variable.
+    pub fn set_upper_bound(&mut self, col: libc::c_int, upper: f64) -> bool {
+        1 == unsafe { lp::set_upbo(self.lprec, col, upper) }
+    }
+
+    /// Get the lower bound of a variable, `None` if `col` is out of bounds.
+    pub fn get_lower_bound(&self, col: libc::c_int) -> Option<f64> {
+        if col < 1 || col > self.num_cols() {
+            None
+        } else {
+            Some(unsafe { lp::get_lowbo(self.lprec, col) })
+        }
+    }
+
+    /// Get the upper bound of a variable, `None` if `col` is out of bounds.
+    pub fn get_upper_bound(&self, col: libc::c_int) -> Option<f64> {
+        if col < 1 || col > self.num_cols() {
+            None
+        } else {
+            Some(unsafe { lp::get_upbo(self.lprec, col) })
+        }
+    }
+
     /// Set the bounds mode to 'tighten'.
     ///
     /// If the bounds mode is `true`, then when `set_bounds`, `set_lower_bound`, or
@@ -447,11 +670,27 @@ impl Problem {
         }
     }
 
+    /// Set a variable to be semi-continuous.
+    ///
+    /// A semi-continuous variable must be zero or lie between its lower and upper bounds.
+    pub fn set_semicontinuous(&mut self, col: libc::c_int, must_be_sc: bool) -> bool {
+        1 == unsafe { lp::set_semicont(self.lprec, col, if must_be_sc { 1 } else { 0 }) }
+    }
+
+    /// Check if a variable is semi-continuous, `None` if `col` is out of bounds.
+    pub fn is_semicontinuous(&self, col: libc::c_int) -> Option<bool> {
+        if col < 1 || col > self.num_cols() {
+            None
+        } else {
+            Some(unsafe { lp::is_semicont(self.lprec, col) } == 1)
+        }
+    }
+
     /// Sets the objective function.
     ///
     /// Asserts that `coeffs` has at least as many elements as the underlying model.
     pub fn set_objective_function(&mut self, coeffs: &[f64]) -> bool {
-        assert!(coeffs.len() >= self.num_cols() as usize + 1);
+        assert!(coeffs.len() > self.num_cols() as usize);
         1 == unsafe { lp::set_obj_fn(self.lprec, coeffs.as_ptr() as *mut _) }
     }
 
@@ -491,27 +730,35 @@ impl Problem {
         }
     }
 
+    /// Set the right hand side of a constraint.
+    ///
+    /// For row 0 this sets the constant of the objective function.
+    pub fn set_rh(&mut self, row: libc::c_int, value: f64) -> bool {
+        1 == unsafe { lp::set_rh(self.lprec, row, value) }
+    }
+
+    /// Get the right hand side of a constraint, `None` if `row` is out of bounds.
+    ///
+    /// For row 0 this is the constant of the objective function.
+    pub fn get_rh(&self, row: libc::c_int) -> Option<f64> {
+        if row < 0 || row > self.num_rows() {
+            None
+        } else {
+            Some(unsafe { lp::get_rh(self.lprec, row) })
+        }
+    }
+
     /// Solve the model.
     pub fn solve(&mut self) -> SolveStatus {
-        use SolveStatus::*;
-        match unsafe { lp::solve(self.lprec) } {
-            -2 => OutOfMemory,
-            -1 => NotRun,
-            0 => Optimal,
-            1 => Suboptimal,
-            2 => Infeasible,
-            3 => Unbounded,
-            4 => Degenerate,
-            5 => NumericalFailure,
-            6 => UserAbort,
-            7 => Timeout,
-            9 => Presolved,
-            10 => ProcFail,
-            11 => ProcBreak,
-            12 => FeasibleFound,
-            13 => NoFeasibleFound,
-            status => panic!("unknown solve status {}", status)
-        }
+        SolveStatus::from_raw(unsafe { lp::solve(self.lprec) })
+    }
+
+    /// Get the status of the most recent `solve`.
+    ///
+    /// Returns `None` for a status that isn't the outcome of a solve, such as the one lpsolve
+    /// reports while a solve is still running, or an error code without a `SolveStatus`.
+    pub fn get_status(&self) -> Option<SolveStatus> {
+        SolveStatus::try_from_raw(unsafe { lp::get_status(self.lprec) })
     }
 
     /// Read out the values assigned to variables from the most recent `solve`.
@@ -528,12 +775,149 @@ impl Problem {
         }
     }
 
+    /// Read out the values of the constraints from the most recent `solve`.
+    ///
+    /// Returns `None` if `rows` does not have at least as many elements as the underlying model
+    /// has rows. Otherwise, returns `Some` with the slice truncated to the number of rows.
+    pub fn get_solution_constraints<'a>(&self, rows: &'a mut [f64]) -> Option<&'a mut [f64]> {
+        let nrows = self.num_rows();
+        if rows.len() < nrows as usize {
+            None
+        } else {
+            unsafe { lp::get_constraints(self.lprec, rows.as_mut_ptr()) };
+            Some(&mut rows[..nrows as usize])
+        }
+    }
+
+    /// Read out the dual values from the most recent `solve`.
+    ///
+    /// The first `num_rows` elements are the duals of the constraints, the following `num_cols`
+    /// elements are the reduced costs of the variables.
+    ///
+    /// Returns `None` if `duals` does not have at least `num_rows + num_cols` elements, or if no
+    /// dual values are available. Otherwise, returns `Some` with the slice truncated to
+    /// `num_rows + num_cols`.
+    pub fn get_dual_solution<'a>(&self, duals: &'a mut [f64]) -> Option<&'a mut [f64]> {
+        let sum = (self.num_rows() + self.num_cols()) as usize;
+        if duals.len() < sum {
+            None
+        } else if 1 == unsafe { lp::get_sensitivity_rhs(self.lprec, duals.as_mut_ptr(), std::ptr::null_mut(),
+                                                        std::ptr::null_mut()) } {
+            Some(&mut duals[..sum])
+        } else {
+            None
+        }
+    }
+
+    /// Read out the sensitivity of the dual values from the most recent `solve`.
+    ///
+    /// Each slice is filled with `num_rows + num_cols` elements, laid out as in
+    /// `get_dual_solution`. `from` and `till` receive the range of the right hand side (or the
+    /// variable value) over which the dual value is valid.
+    ///
+    /// Asserts that each slice is large enough. Returns `false` if no sensitivity information is
+    /// available.
+    pub fn get_sensitivity_rhs(&self, duals: &mut [f64], from: &mut [f64], till: &mut [f64]) -> bool {
+        let sum = (self.num_rows() + self.num_cols()) as usize;
+        assert!(duals.len() >= sum && from.len() >= sum && till.len() >= sum);
+        1 == unsafe { lp::get_sensitivity_rhs(self.lprec, duals.as_mut_ptr(), from.as_mut_ptr(), till.as_mut_ptr()) }
+    }
+
+    /// Read out the sensitivity of the objective function from the most recent `solve`.
+    ///
+    /// Each slice is filled with `num_cols` elements. `from` and `till` receive the range over
+    /// which each objective coefficient can vary without changing the solution, and `from_value`
+    /// the objective function value when the coefficient reaches its lower limit.
+    ///
+    /// Asserts that each slice is large enough. Returns `false` if no sensitivity information is
+    /// available.
+    pub fn get_sensitivity_obj(&self, from: &mut [f64], till: &mut [f64], from_value: &mut [f64]) -> bool {
+        let cols = self.num_cols() as usize;
+        assert!(from.len() >= cols && till.len() >= cols && from_value.len() >= cols);
+        1 == unsafe { lp::get_sensitivity_objex(self.lprec, from.as_mut_ptr(), till.as_mut_ptr(),
+                                                from_value.as_mut_ptr(), std::ptr::null_mut()) }
+    }
+
+    /// Read out the final basis of the most recent `solve`.
+    ///
+    /// `basis[0]` is unused. The next `num_rows` elements receive the indices of the basic
+    /// variables, and with `nonbasic` the remaining `num_cols` those of the nonbasic ones. Row `i`
+    /// has index `i` and column `j` has index `num_rows + j`. An index is negative if the variable
+    /// is at its lower bound.
+    ///
+    /// Asserts that `basis` is large enough. Returns `false` if no basis is available.
+    pub fn get_basis(&self, basis: &mut [libc::c_int], nonbasic: bool) -> bool {
+        assert!(basis.len() > self.basis_len(nonbasic));
+        1 == unsafe { lp::get_basis(self.lprec, basis.as_mut_ptr(), if nonbasic { 1 } else { 0 }) }
+    }
+
+    /// Sets the starting basis for the next `solve`, laid out as in `get_basis`.
+    ///
+    /// Asserts that `basis` is large enough. Returns `false` if it isn't a valid basis.
+    pub fn set_basis(&mut self, basis: &[libc::c_int], nonbasic: bool) -> bool {
+        assert!(basis.len() > self.basis_len(nonbasic));
+        1 == unsafe { lp::set_basis(self.lprec, basis.as_ptr() as *mut _, if nonbasic { 1 } else { 0 }) }
+    }
+
+    /// Resets the starting basis to the one with all slack variables basic.
+    pub fn default_basis(&mut self) {
+        unsafe { lp::default_basis(self.lprec) }
+    }
+
+    fn basis_len(&self, nonbasic: bool) -> usize {
+        (self.num_rows() + if nonbasic { self.num_cols() } else { 0 }) as usize
+    }
+
+    /// Set the name of the model.
+    pub fn set_name(&mut self, name: &CStr) -> bool {
+        1 == unsafe { lp::set_lp_name(self.lprec, name.as_ptr() as *mut _) }
+    }
+
+    /// Get the name of the model. The name is empty if none was set.
+    pub fn get_name(&self) -> String {
+        unsafe { from_cstr(lp::get_lp_name(self.lprec)) }.unwrap_or_default()
+    }
+
+    /// Set the name of a column.
+    pub fn set_col_name(&mut self, col: libc::c_int, name: &CStr) -> bool {
+        1 == unsafe { lp::set_col_name(self.lprec, col, name.as_ptr() as *mut _) }
+    }
+
+    /// Get the name of a column, `None` if `col` is out of bounds.
+    ///
+    /// Columns which were never named get a default name of the form `C1`, `C2`, ...
+    pub fn get_col_name(&self, col: libc::c_int) -> Option<String> {
+        if col < 1 || col > self.num_cols() {
+            None
+        } else {
+            unsafe { from_cstr(lp::get_col_name(self.lprec, col)) }
+        }
+    }
+
+    /// Set the name of a row.
+    pub fn set_row_name(&mut self, row: libc::c_int, name: &CStr) -> bool {
+        1 == unsafe { lp::set_row_name(self.lprec, row, name.as_ptr() as *mut _) }
+    }
+
+    /// Get the name of a row, `None` if `row` is out of bounds.
+    ///
+    /// Rows which were never named get a default name of the form `R1`, `R2`, ...
+    pub fn get_row_name(&self, row: libc::c_int) -> Option<String> {
+        if row < 0 || row > self.num_rows() {
+            None
+        } else {
+            unsafe { from_cstr(lp::get_row_name(self.lprec, row)) }
+        }
+    }
+
     /// Construct a wrapper for a pre-existing `lprec`.
     ///
+    /// # Safety
+    ///
     /// This is unsafe as the pointer is not null-checked etc.
     pub unsafe fn from_lprec(lprec: *mut lp::lprec) -> Problem {
         Problem {
-            lprec: lprec
+            lprec
         }
     }
 
@@ -551,6 +935,11 @@ impl Problem {
     pub fn num_rows(&self) -> libc::c_int {
         unsafe { lp::get_Nrows(self.lprec) }
     }
+
+    /// The number of nonzero coefficients in the constraint matrix, not counting the objective.
+    pub fn num_nonzeros(&self) -> libc::c_int {
+        unsafe { lp::get_nonzeros(self.lprec) }
+    }
 }
 
 impl Drop for Problem {
@@ -579,4 +968,32 @@ mod tests {
         let mut lp = Problem::new(0, 0).unwrap();
         assert_eq!(lp.solve(), ::SolveStatus::NotRun);
     }
+
+    #[test]
+    fn semicontinuous() {
+        let mut lp = Problem::new(0, 2).unwrap();
+        assert!(lp.set_semicontinuous(2, true));
+        assert_eq!(lp.is_semicontinuous(1), Some(false));
+        assert_eq!(lp.is_semicontinuous(2), Some(true));
+        assert_eq!(lp.is_semicontinuous(0), None);
+        assert_eq!(lp.is_semicontinuous(3), None);
+    }
+
+    #[test]
+    fn status() {
+        let mut lp = Problem::new(0, 1).unwrap();
+        assert_eq!(lp.solve(), ::SolveStatus::Optimal);
+        assert_eq!(lp.get_status(), Some(::SolveStatus::Optimal));
+        // RUNNING, which lpsolve reports from its callbacks.
+        assert_eq!(::SolveStatus::try_from_raw(8), None);
+    }
+
+    #[test]
+    fn write_lp() {
+        let mut lp = Problem::new(0, 1).unwrap();
+        lp.set_maxim();
+        let mut out = Vec::new();
+        assert!(lp.write_lp(&mut out));
+        assert!(String::from_utf8(out).unwrap().contains("max:"));
+    }
 }
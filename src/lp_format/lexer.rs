@@ -0,0 +1,320 @@
+//! Tokenizer for the lp-format.
+
+use super::{DeclarationKind, ParseError, Span};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Number(f64),
+    Ident(String),
+    /// `+inf` or `-inf`, `true` if negative.
+    Inf(bool),
+    /// A single `+` or `-`, `true` if negative.
+    Sign(bool),
+    Minimize,
+    Maximize,
+    Section(DeclarationKind),
+    /// `sos1`, `sos2` or the generic `sos`, which has type 0.
+    SosSection(i32),
+    Le,
+    Ge,
+    Eq,
+    Colon,
+    Comma,
+    Star,
+    Semicolon,
+    Eof,
+}
+
+pub struct Lexer<'a> {
+    src: &'a str,
+    pos: usize,
+    line: usize,
+    line_start: usize,
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_ascii_alphabetic()
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "_[]{}/.&#$%~'@^".contains(c)
+}
+
+/// Words the lexer reads as something other than an identifier in some position: section keywords
+/// at the start of a line, `min` and `max` before a colon and infinities after a sign.
+const RESERVED: &[&str] = &["int", "integer", "bin", "binary", "sec", "sin", "free", "sos", "sos1", "sos2", "min",
+                            "minimize", "minimise", "max", "maximize", "maximise", "inf", "infinite", "infinity"];
+
+/// Returns `true` if `name` is read back as the same identifier wherever the writer puts it.
+pub fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().map_or(false, is_ident_start) && chars.all(is_ident_char)
+        && !RESERVED.contains(&name.to_ascii_lowercase().as_str())
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(src: &'a str) -> Lexer<'a> {
+        Lexer { src, pos: 0, line: 1, line_start: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.line_start = self.pos;
+        }
+        Some(c)
+    }
+
+    fn span_from(&self, start: usize, line: usize, line_start: usize) -> Span {
+        Span { start, end: self.pos, line, column: start - line_start + 1 }
+    }
+
+    fn error(&self, message: String, start: usize) -> ParseError {
+        ParseError::new(message, Span { start, end: self.pos, line: self.line, column: start - self.line_start + 1 })
+    }
+
+    /// Skips whitespace and comments, returning whether only whitespace precedes the next token on
+    /// its line.
+    fn skip_trivia(&mut self) -> Result<bool, ParseError> {
+        let mut at_line_start = self.src[self.line_start..self.pos].trim().is_empty();
+        loop {
+            let rest = self.rest();
+            if let Some(comment) = rest.strip_prefix("/*") {
+                let start = self.pos;
+                match comment.find("*/") {
+                    Some(end) => {
+                        let text = &comment[..end];
+                        if let Some(newline) = text.rfind('\n') {
+                            self.line += text.matches('\n').count();
+                            self.line_start = start + 2 + newline + 1;
+                        }
+                        self.pos = start + 2 + end + 2;
+                    }
+                    None => return Err(self.error("unterminated comment".to_string(), start)),
+                }
+                at_line_start = false;
+            } else if rest.starts_with("//") {
+                while self.peek().map_or(false, |c| c != '\n') {
+                    self.bump();
+                }
+            } else {
+                match self.peek() {
+                    Some('\n') => {
+                        self.bump();
+                        at_line_start = true;
+                    }
+                    Some(c) if c.is_whitespace() => {
+                        self.bump();
+                    }
+                    _ => return Ok(at_line_start),
+                }
+            }
+        }
+    }
+
+    fn ident(&mut self) -> &'a str {
+        let start = self.pos;
+        while self.peek().map_or(false, is_ident_char) {
+            self.bump();
+        }
+        &self.src[start..self.pos]
+    }
+
+    /// Returns `true` if the next non-blank character on this line is a colon.
+    fn followed_by_colon(&self) -> bool {
+        self.rest().trim_start_matches([' ', '\t']).starts_with(':')
+    }
+
+    fn skip_colon(&mut self) {
+        while self.peek() != Some(':') {
+            self.bump();
+        }
+        self.bump();
+    }
+
+    fn number(&mut self) -> Result<f64, ParseError> {
+        let start = self.pos;
+        let bytes = self.src.as_bytes();
+        let mut end = self.pos;
+        while end < bytes.len() && bytes[end].is_ascii_digit() {
+            end += 1;
+        }
+        if end < bytes.len() && bytes[end] == b'.' {
+            end += 1;
+            while end < bytes.len() && bytes[end].is_ascii_digit() {
+                end += 1;
+            }
+        }
+        if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+            let mut exp = end + 1;
+            if exp < bytes.len() && (bytes[exp] == b'+' || bytes[exp] == b'-') {
+                exp += 1;
+            }
+            if exp < bytes.len() && bytes[exp].is_ascii_digit() {
+                end = exp;
+                while end < bytes.len() && bytes[end].is_ascii_digit() {
+                    end += 1;
+                }
+            }
+        }
+        self.pos = end;
+        self.src[start..end].parse().map_err(|_| self.error(format!("invalid number `{}`", &self.src[start..end]), start))
+    }
+
+    /// Checks for `inf`, `infinite` or `infinity` directly following a sign.
+    fn infinity_follows(&self) -> usize {
+        let rest = &self.rest()[1..];
+        let len = rest.find(|c| !is_ident_char(c)).unwrap_or(rest.len());
+        match rest[..len].to_ascii_lowercase().as_str() {
+            "inf" | "infinite" | "infinity" => len,
+            _ => 0,
+        }
+    }
+
+    pub fn next_token(&mut self) -> Result<(Token, Span), ParseError> {
+        let at_line_start = self.skip_trivia()?;
+        let (start, line, line_start) = (self.pos, self.line, self.line_start);
+        let c = match self.peek() {
+            Some(c) => c,
+            None => return Ok((Token::Eof, self.span_from(start, line, line_start))),
+        };
+        let token = match c {
+            '+' | '-' => {
+                let len = self.infinity_follows();
+                self.bump();
+                if len > 0 {
+                    self.pos += len;
+                    Token::Inf(c == '-')
+                } else {
+                    Token::Sign(c == '-')
+                }
+            }
+            '0'..='9' | '.' => Token::Number(self.number()?),
+            '<' => {
+                self.bump();
+                if self.peek() == Some('=') {
+                    self.bump();
+                }
+                Token::Le
+            }
+            '>' => {
+                self.bump();
+                if self.peek() == Some('=') {
+                    self.bump();
+                }
+                Token::Ge
+            }
+            '=' => {
+                self.bump();
+                match self.peek() {
+                    Some('<') => {
+                        self.bump();
+                        Token::Le
+                    }
+                    Some('>') => {
+                        self.bump();
+                        Token::Ge
+                    }
+                    _ => Token::Eq,
+                }
+            }
+            ':' => {
+                self.bump();
+                Token::Colon
+            }
+            ',' => {
+                self.bump();
+                Token::Comma
+            }
+            '*' => {
+                self.bump();
+                Token::Star
+            }
+            ';' => {
+                self.bump();
+                Token::Semicolon
+            }
+            c if is_ident_start(c) => {
+                let word = self.ident();
+                let lower = word.to_ascii_lowercase();
+                let colon = self.followed_by_colon();
+                match lower.as_str() {
+                    "min" | "minimize" | "minimise" if colon => {
+                        self.skip_colon();
+                        Token::Minimize
+                    }
+                    "max" | "maximize" | "maximise" if colon => {
+                        self.skip_colon();
+                        Token::Maximize
+                    }
+                    _ if colon || !at_line_start => Token::Ident(word.to_string()),
+                    "int" | "integer" => Token::Section(DeclarationKind::Int),
+                    "bin" | "binary" => Token::Section(DeclarationKind::Bin),
+                    "sec" => Token::Section(DeclarationKind::Sec),
+                    "sin" => Token::Section(DeclarationKind::Sin),
+                    "free" => Token::Section(DeclarationKind::Free),
+                    "sos" => Token::SosSection(0),
+                    "sos1" => Token::SosSection(1),
+                    "sos2" => Token::SosSection(2),
+                    _ => Token::Ident(word.to_string()),
+                }
+            }
+            c => {
+                self.bump();
+                return Err(self.error(format!("unexpected character `{}`", c), start));
+            }
+        };
+        Ok((token, self.span_from(start, line, line_start)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Lexer, Token};
+    use super::super::DeclarationKind;
+
+    fn tokens(src: &str) -> Vec<Token> {
+        let mut lexer = Lexer::new(src);
+        let mut out = Vec::new();
+        loop {
+            match lexer.next_token().unwrap().0 {
+                Token::Eof => return out,
+                t => out.push(t),
+            }
+        }
+    }
+
+    #[test]
+    fn operators_and_numbers() {
+        assert_eq!(tokens("3x1 >= -inf =< 2.5e3 => .5"),
+                   vec![Token::Number(3.0), Token::Ident("x1".to_string()), Token::Ge, Token::Inf(true),
+                        Token::Le, Token::Number(2500.0), Token::Ge, Token::Number(0.5)]);
+    }
+
+    #[test]
+    fn keywords() {
+        assert_eq!(tokens("max : x; /* c */\nint x\n// int y\nR1: int"),
+                   vec![Token::Maximize, Token::Ident("x".to_string()), Token::Semicolon,
+                        Token::Section(DeclarationKind::Int), Token::Ident("x".to_string()),
+                        Token::Ident("R1".to_string()), Token::Colon, Token::Ident("int".to_string())]);
+    }
+
+    #[test]
+    fn non_ascii_comment() {
+        let src = "/* café,\n naïve */ x >= 1;";
+        assert_eq!(tokens(src),
+                   vec![Token::Ident("x".to_string()), Token::Ge, Token::Number(1.0), Token::Semicolon]);
+        let (_, span) = Lexer::new(src).next_token().unwrap();
+        assert_eq!((span.line, &src[span.start..span.end]), (2, "x"));
+    }
+}
@@ -0,0 +1,316 @@
+//! Applying the statements of an `LpFile` to build a `ModelData`.
+
+use std::collections::HashMap;
+
+use libc::c_int;
+
+use {ColumnData, ConstraintType, ModelData, RowData, SOSData};
+use super::{Declaration, DeclarationKind, Expression, Ident, LpFile, ParseError, Relation, SosSet, Span, Term};
+
+const INFINITY: f64 = 1e30;
+const TOLERANCE: f64 = 1e-10;
+
+fn flip(op: ConstraintType) -> ConstraintType {
+    match op {
+        ConstraintType::Le => ConstraintType::Ge,
+        ConstraintType::Ge => ConstraintType::Le,
+        op => op,
+    }
+}
+
+fn clamp(value: f64) -> f64 {
+    value.clamp(-INFINITY, INFINITY)
+}
+
+fn error<T>(message: String, span: Span) -> Result<T, ParseError> {
+    Err(ParseError::new(message, span))
+}
+
+struct Column {
+    name: String,
+    lower: Option<f64>,
+    upper: Option<f64>,
+    integer: bool,
+    semicontinuous: bool,
+    free: bool,
+}
+
+struct Row {
+    name: Option<String>,
+    coefficients: Vec<(c_int, f64)>,
+    op: ConstraintType,
+    rhs: f64,
+    /// The operator and value of the other side of a ranged row.
+    range: Option<(ConstraintType, f64)>,
+}
+
+#[derive(Default)]
+struct Builder {
+    columns: Vec<Column>,
+    column_index: HashMap<String, c_int>,
+    rows: Vec<Row>,
+    row_index: HashMap<String, usize>,
+    sos: Vec<SOSData>,
+}
+
+impl Builder {
+    fn column(&mut self, name: &str) -> c_int {
+        if let Some(&col) = self.column_index.get(name) {
+            return col;
+        }
+        self.columns.push(Column {
+            name: name.to_string(),
+            lower: None,
+            upper: None,
+            integer: false,
+            semicontinuous: false,
+            free: false,
+        });
+        let col = self.columns.len() as c_int;
+        self.column_index.insert(name.to_string(), col);
+        col
+    }
+
+    /// Collects the variable terms of `expressions`, each scaled by its factor, merging duplicates.
+    fn linear(&mut self, expressions: &[(&Expression, f64)]) -> Vec<(c_int, f64)> {
+        let mut coefficients: Vec<(c_int, f64)> = Vec::new();
+        let mut position = HashMap::new();
+        for &(expression, factor) in expressions {
+            for term in &expression.terms {
+                if let Some(ref var) = term.variable {
+                    let col = self.column(&var.name);
+                    let idx = *position.entry(col).or_insert_with(|| {
+                        coefficients.push((col, 0.0));
+                        coefficients.len() - 1
+                    });
+                    coefficients[idx].1 += factor * term.coefficient;
+                }
+            }
+        }
+        coefficients.retain(|&(_, v)| v != 0.0);
+        coefficients
+    }
+
+    /// Finds a row by its explicit name, or by its default name `R{i}`.
+    fn find_row(&self, name: &str) -> Option<usize> {
+        self.row_index.get(name).cloned().or_else(|| {
+            if !name.starts_with('R') {
+                return None;
+            }
+            let idx = name[1..].parse::<usize>().ok()?;
+            if idx >= 1 && idx <= self.rows.len() && self.rows[idx - 1].name.is_none() {
+                Some(idx - 1)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn relation(&mut self, rel: &Relation) -> Result<(), ParseError> {
+        if rel.lhs.terms.is_empty() {
+            return match rel.name {
+                Some(ref name) if rel.range.is_none() && !rel.rhs.terms.is_empty() && !rel.rhs.has_variables() => {
+                    let row = match self.find_row(&name.name) {
+                        Some(row) => row,
+                        None => return error(format!("range for undefined row `{}`", name.name), name.span),
+                    };
+                    self.range(row, rel.op, rel.rhs.constant(), rel.span)
+                }
+                _ => error("expected an expression before the operator".to_string(), rel.lhs.span),
+            };
+        }
+
+        if let Some((op2, ref last)) = rel.range {
+            if rel.lhs.has_variables() {
+                return error("a double inequality must start with a constant".to_string(), rel.lhs.span);
+            }
+            if last.has_variables() || last.terms.is_empty() {
+                return error("a double inequality must end with a constant".to_string(), last.span);
+            }
+            if !rel.rhs.has_variables() {
+                return error("the relation has no variables".to_string(), rel.span);
+            }
+            let constant = rel.rhs.constant();
+            let (op1, first) = (flip(rel.op), rel.lhs.constant() - constant);
+            let second = last.constant() - constant;
+            if let Some(term) = self.single_term(rel) {
+                self.bound(term, op1, first, rel.span)?;
+                return self.bound(term, op2, second, rel.span);
+            }
+            // Like lpsolve, the first operator decides the type of the row.
+            let coefficients = self.linear(&[(&rel.rhs, 1.0)]);
+            let row = self.add_row(rel.name.as_ref(), coefficients, op1, first)?;
+            return self.range(row, op2, second, rel.span);
+        }
+
+        if rel.rhs.terms.is_empty() {
+            return error("expected an expression after the operator".to_string(), rel.rhs.span);
+        }
+        let (lhs_vars, rhs_vars) = (rel.lhs.has_variables(), rel.rhs.has_variables());
+        if !lhs_vars && !rhs_vars {
+            return error("the relation has no variables".to_string(), rel.span);
+        }
+        if let Some(term) = self.single_term(rel) {
+            return if lhs_vars {
+                self.bound(term, rel.op, rel.rhs.constant() - rel.lhs.constant(), rel.span)
+            } else {
+                self.bound(term, flip(rel.op), rel.lhs.constant() - rel.rhs.constant(), rel.span)
+            };
+        }
+        // Like lpsolve, `3 >= x + y` is stored as `x + y <= 3` rather than `-x - y >= -3`.
+        let (coefficients, op, rhs) = if lhs_vars {
+            (self.linear(&[(&rel.lhs, 1.0), (&rel.rhs, -1.0)]), rel.op, rel.rhs.constant() - rel.lhs.constant())
+        } else {
+            (self.linear(&[(&rel.rhs, 1.0)]), flip(rel.op), rel.lhs.constant() - rel.rhs.constant())
+        };
+        self.add_row(rel.name.as_ref(), coefficients, op, rhs).map(|_| ())
+    }
+
+    /// The only variable term of an unlabeled relation, which makes it a bound.
+    fn single_term<'a>(&self, rel: &'a Relation) -> Option<&'a Term> {
+        if rel.name.is_some() {
+            return None;
+        }
+        let mut terms = rel.lhs.terms.iter().chain(rel.rhs.terms.iter()).filter(|t| t.variable.is_some());
+        match (terms.next(), terms.next()) {
+            (Some(term), None) => Some(term),
+            _ => None,
+        }
+    }
+
+    fn add_row(&mut self, name: Option<&Ident>, coefficients: Vec<(c_int, f64)>, op: ConstraintType, rhs: f64)
+               -> Result<usize, ParseError> {
+        let row = self.rows.len();
+        if let Some(name) = name {
+            if self.find_row(&name.name).is_some() {
+                return error(format!("there is already a row named `{}`", name.name), name.span);
+            }
+            self.row_index.insert(name.name.clone(), row);
+        }
+        self.rows.push(Row { name: name.map(|n| n.name.clone()), coefficients, op, rhs: clamp(rhs), range: None });
+        Ok(row)
+    }
+
+    fn range(&mut self, row: usize, op: ConstraintType, value: f64, span: Span) -> Result<(), ParseError> {
+        let row = &mut self.rows[row];
+        if row.range.is_some() {
+            return error("there is already a range on this row".to_string(), span);
+        }
+        if row.op == ConstraintType::Eq || op == ConstraintType::Eq {
+            return error("a range can't be combined with `=`".to_string(), span);
+        }
+        if row.op == op {
+            return error("the operator of a range must differ from the operator of its row".to_string(), span);
+        }
+        if (op == ConstraintType::Ge && value > row.rhs + TOLERANCE)
+            || (op == ConstraintType::Le && value < row.rhs - TOLERANCE) {
+            return error("the range conflicts with the right hand side of the row".to_string(), span);
+        }
+        if value.abs() < INFINITY {
+            row.range = Some((op, value));
+        }
+        Ok(())
+    }
+
+    fn bound(&mut self, term: &Term, op: ConstraintType, value: f64, span: Span) -> Result<(), ParseError> {
+        let var = term.variable.as_ref().expect("bound on a constant");
+        let col = self.column(&var.name) as usize - 1;
+        if term.coefficient == 0.0 {
+            let harmless = value == 0.0 || (value > 0.0 && op == ConstraintType::Le)
+                || (value < 0.0 && op == ConstraintType::Ge);
+            return if harmless {
+                Ok(())
+            } else {
+                error(format!("variable `{}` has a coefficient of 0 in a bound", var.name), span)
+            };
+        }
+        let op = if term.coefficient < 0.0 { flip(op) } else { op };
+        let value = clamp(value / term.coefficient);
+        let column = &mut self.columns[col];
+        if op != ConstraintType::Le && column.lower.map_or(true, |lower| value > lower - TOLERANCE) {
+            column.lower = Some(value);
+        }
+        if op != ConstraintType::Ge && column.upper.map_or(true, |upper| value < upper + TOLERANCE) {
+            column.upper = Some(value);
+        }
+        if let (Some(lower), Some(upper)) = (column.lower, column.upper) {
+            if upper + TOLERANCE < lower {
+                return error(format!("the bound contradicts earlier bounds on `{}`", var.name), span);
+            }
+        }
+        Ok(())
+    }
+
+    fn declaration(&mut self, decl: &Declaration) {
+        for var in &decl.variables {
+            let col = self.column(&var.name) as usize - 1;
+            let column = &mut self.columns[col];
+            match decl.kind {
+                DeclarationKind::Int => column.integer = true,
+                DeclarationKind::Bin => {
+                    column.integer = true;
+                    column.lower = Some(0.0);
+                    column.upper = Some(1.0);
+                }
+                DeclarationKind::Sec => column.semicontinuous = true,
+                DeclarationKind::Sin => {
+                    column.semicontinuous = true;
+                    column.integer = true;
+                }
+                DeclarationKind::Free => column.free = true,
+            }
+        }
+    }
+
+    fn sos(&mut self, set: &SosSet) {
+        let priority = set.priority.unwrap_or_else(|| self.sos.last().map_or(1, |s| s.priority + 1));
+        let name = set.name.as_ref().map_or_else(|| format!("SOS{}", self.sos.len() + 1), |n| n.name.clone());
+        let mut variables = Vec::new();
+        let mut weights: Vec<f64> = Vec::new();
+        for member in &set.members {
+            variables.push(self.column(&member.variable.name));
+            let weight = member.weight.unwrap_or_else(|| weights.last().map_or(1.0, |w| w + 1.0));
+            weights.push(weight);
+        }
+        self.sos.push(SOSData { name, sostype: set.sostype, priority, variables, weights });
+    }
+}
+
+pub fn lower(file: &LpFile) -> Result<ModelData, ParseError> {
+    let mut builder = Builder::default();
+    let objective = builder.linear(&[(&file.objective.expression, 1.0)]);
+    for statement in &file.statements {
+        match *statement {
+            super::Statement::Relation(ref rel) => builder.relation(rel)?,
+            super::Statement::Declaration(ref decl) => builder.declaration(decl),
+            super::Statement::Sos(ref set) => builder.sos(set),
+        }
+    }
+
+    let rows = builder.rows.into_iter().enumerate().map(|(i, row)| {
+        let range = row.range.map(|(_, value)| (row.rhs - value).abs());
+        let name = row.name.filter(|name| *name != format!("R{}", i + 1));
+        RowData { name, coefficients: row.coefficients, kind: row.op, rhs: row.rhs, range }
+    }).collect();
+    let columns = builder.columns.into_iter().enumerate().map(|(j, col)| {
+        let lower = if col.free { -INFINITY } else { col.lower.unwrap_or(0.0) };
+        let upper = col.upper.unwrap_or(INFINITY);
+        ColumnData {
+            name: if col.name == format!("C{}", j + 1) { None } else { Some(col.name) },
+            lower,
+            upper,
+            integer: col.integer,
+            semicontinuous: col.semicontinuous,
+        }
+    }).collect();
+    Ok(ModelData {
+        name: String::new(),
+        maximize: file.objective.maximize,
+        objective,
+        objective_constant: file.objective.expression.constant(),
+        infinity: INFINITY,
+        rows,
+        columns,
+        sos: builder.sos,
+    })
+}
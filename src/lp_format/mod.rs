@@ -0,0 +1,383 @@
+//! A pure Rust reader and writer for the lpsolve lp-format.
+//!
+//! `Problem::read_lp` hands a file to the C parser, which reports problems by printing to standard
+//! out. This module parses the same format into a syntax tree, `LpFile`, with a `Span` attached to
+//! every part of it, so errors can point at the offending text. An `LpFile` can be lowered to a
+//! `ModelData` or directly to a `Problem`, and can be printed back out with its `Display` impl.
+//!
+//! ```
+//! use lpsolve::lp_format;
+//!
+//! let file = lp_format::parse("max: 143 x + 60 y;\n\
+//!                              land: 120 x + 210 y <= 15000;\n\
+//!                              x <= 75;\n\
+//!                              int y;").unwrap();
+//! let data = file.to_model_data().unwrap();
+//! assert_eq!(data.columns[0].upper, 75.0);
+//!
+//! let err = lp_format::parse("max: x;\nc1: x +* y >= 2;").unwrap_err();
+//! assert_eq!((err.span.line, err.span.column), (2, 8));
+//! ```
+//!
+//! The reading rules follow lpsolve:
+//!
+//! * The first statement is the objective function, optionally prefixed with `max:` or `min:`.
+//!   Without a prefix it is minimized.
+//! * A relation with a single variable and no label is a bound on that variable, not a row.
+//!   Bounds only ever tighten the existing bound. Use a label (`c1: x >= 1;`) to get a row.
+//! * A bound is an error if it contradicts a bound given earlier, but not the default lower bound
+//!   of 0: `x <= -5;` on its own makes the model infeasible, as it does in lpsolve.
+//! * `-5 <= x + y <= 10;` is a ranged row, and `c1: <= 10;` adds a range to an existing row.
+//! * Values at or beyond `1e30` in magnitude, as well as `+inf` and `-inf`, are infinite.
+//! * `int`, `bin`, `sec`, `sin` and `free` sections must start a line. `sos1` and `sos2`
+//!   sections hold sets like `s1: x:5, y:10;`. A generic `sos` section gives the type of each
+//!   set after `<=`, optionally followed by the priority: `s1: x:5, y:10 <= 2:3;`. Omitted
+//!   weights and priorities count up from the previous one.
+//!
+//! Unlike lpsolve, a variable that first appears in a declaration is added as a new column instead
+//! of being ignored. `free` only removes the lower bound, as in later lpsolve releases; the bundled
+//! 5.5.2.0 reader drops the upper bound as well.
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use libc::c_int;
+
+use {ConstraintType, ModelData, Problem, SOSType};
+
+mod lexer;
+mod parser;
+mod lower;
+mod writer;
+
+pub(crate) use self::writer::format_number;
+
+/// A region of the source text.
+///
+/// `start` and `end` are byte offsets, `line` and `column` are 1-based and refer to `start`.
+/// Syntax trees that weren't parsed from text carry a default (all zero) span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// An error in the syntax or meaning of an lp-format model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub span: Span,
+}
+
+impl ParseError {
+    pub(crate) fn new(message: String, span: Span) -> ParseError {
+        ParseError { message, span }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.span.line, self.span.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// A name with its location.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Ident {
+    pub name: String,
+    pub span: Span,
+}
+
+impl Ident {
+    /// An identifier without a source location.
+    pub fn new<S: Into<String>>(name: S) -> Ident {
+        Ident { name: name.into(), span: Span::default() }
+    }
+}
+
+/// One term of a linear expression, `coefficient variable` or a bare constant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Term {
+    pub coefficient: f64,
+    /// `None` for a constant term.
+    pub variable: Option<Ident>,
+    pub span: Span,
+}
+
+/// A sum of terms, as written. Duplicate variables and constants are not merged.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Expression {
+    pub terms: Vec<Term>,
+    pub span: Span,
+}
+
+impl Expression {
+    /// Returns `true` if any term has a variable.
+    pub fn has_variables(&self) -> bool {
+        self.terms.iter().any(|t| t.variable.is_some())
+    }
+
+    /// The sum of the constant terms.
+    pub fn constant(&self) -> f64 {
+        self.terms.iter().filter(|t| t.variable.is_none()).map(|t| t.coefficient).sum()
+    }
+}
+
+/// The objective function.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Objective {
+    pub maximize: bool,
+    pub expression: Expression,
+    pub span: Span,
+}
+
+/// A constraint, bound or range statement.
+///
+/// `lhs op rhs;` is the common form. A double inequality `lhs op rhs op2 range;` stores the
+/// second operator and the last expression in `range`. A statement that only sets a range on an
+/// existing row, `name: op value;`, has an empty `lhs`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relation {
+    pub name: Option<Ident>,
+    pub lhs: Expression,
+    pub op: ConstraintType,
+    pub rhs: Expression,
+    pub range: Option<(ConstraintType, Expression)>,
+    pub span: Span,
+}
+
+/// The kind of a declaration section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeclarationKind {
+    /// `int`: the variables must be integer.
+    Int,
+    /// `bin`: the variables must be integer and between 0 and 1.
+    Bin,
+    /// `sec`: the variables are semi-continuous.
+    Sec,
+    /// `sin`: the variables are semi-continuous and integer.
+    Sin,
+    /// `free`: the variables have no lower bound. An upper bound is kept.
+    Free,
+}
+
+/// A declaration like `int x, y;`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Declaration {
+    pub kind: DeclarationKind,
+    pub variables: Vec<Ident>,
+    pub span: Span,
+}
+
+/// A member of a special ordered set, with its weight if one was given.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SosMember {
+    pub variable: Ident,
+    pub weight: Option<f64>,
+}
+
+/// A special ordered set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SosSet {
+    pub name: Option<Ident>,
+    pub sostype: SOSType,
+    /// `None` if the priority wasn't given.
+    pub priority: Option<c_int>,
+    pub members: Vec<SosMember>,
+    pub span: Span,
+}
+
+/// A statement following the objective function.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Relation(Relation),
+    Declaration(Declaration),
+    Sos(SosSet),
+}
+
+/// The syntax tree of an lp-format model.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LpFile {
+    pub objective: Objective,
+    pub statements: Vec<Statement>,
+}
+
+/// Parse lp-format text into a syntax tree.
+///
+/// This only checks the syntax. Errors in meaning, like a range on an undefined row, are
+/// reported by `LpFile::to_model_data`.
+pub fn parse(src: &str) -> Result<LpFile, ParseError> {
+    parser::Parser::new(src)?.file()
+}
+
+impl LpFile {
+    /// Apply the statements in order to build a model.
+    pub fn to_model_data(&self) -> Result<ModelData, ParseError> {
+        lower::lower(self)
+    }
+
+    /// Build a `Problem` from the model.
+    ///
+    /// Fails with an error at the start of the file if lpsolve rejects the model.
+    pub fn to_problem(&self) -> Result<Problem, ParseError> {
+        self.to_model_data()?.to_problem()
+            .ok_or_else(|| ParseError::new("lpsolve rejected the model".to_string(), Span::default()))
+    }
+
+    /// Build the syntax tree for a model, naming rows and columns `R{i}` and `C{j}` where the
+    /// model doesn't name them.
+    ///
+    /// Fails if a name wouldn't be read back as written, because it has characters outside of
+    /// identifiers, like `x[a,1]`, or is a keyword, like `int` or `inf`.
+    pub fn from_model_data(data: &ModelData) -> Result<LpFile, ParseError> {
+        writer::from_model_data(data)
+    }
+
+    /// Build the syntax tree for a problem.
+    pub fn from_problem(problem: &Problem) -> Result<LpFile, ParseError> {
+        LpFile::from_model_data(&ModelData::from_problem(problem))
+    }
+}
+
+impl<'a> TryFrom<&'a ModelData> for LpFile {
+    type Error = ParseError;
+
+    fn try_from(data: &'a ModelData) -> Result<LpFile, ParseError> {
+        LpFile::from_model_data(data)
+    }
+}
+
+impl fmt::Display for LpFile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writer::write(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use std::fs;
+
+    use {ModelData, Problem, SOSType, SolveStatus, Verbosity};
+    use super::{parse, LpFile};
+
+    const MODEL: &str = "/* test */ max: 143 x + 60 y + 2 z - 3;\n\
+                         land: 120 x + 210 y <= 15000;\n\
+                         110 x + 30 y <= 4000 - 2 z;\n\
+                         -5 <= x + y + z <= 75;\n\
+                         c4: 3 >= x - y;\n\
+                         c4: >= -8;\n\
+                         r5: 2 y >= 1;\n\
+                         x <= 60; x >= 1; -y >= -50;\n\
+                         3 x >= 1;\n\
+                         z >= -1e30;\n\
+                         int y\n\
+                         ;\n\
+                         sos2\n\
+                         s1: x:5, y:10, z:15;\n";
+
+    fn c_read_lp(text: &str, name: &str) -> Problem {
+        let path = ::std::env::temp_dir().join(format!("lpsolve-lp-format-{}.lp", name));
+        fs::write(&path, text).unwrap();
+        let path = CString::new(path.to_str().unwrap()).unwrap();
+        Problem::read_lp(&path, Verbosity::Critical, &CString::new("").unwrap()).unwrap()
+    }
+
+    fn lp_text(lp: &Problem) -> String {
+        let mut out = Vec::new();
+        assert!(lp.write_lp(&mut out));
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn matches_c_reader() {
+        let data = parse(MODEL).unwrap().to_model_data().unwrap();
+        assert_eq!(data.objective_constant, -3.0);
+        assert_eq!(data.rows.len(), 5);
+        assert_eq!(data.rows[2].range, Some(80.0));
+        assert_eq!((data.columns[0].lower, data.columns[0].upper), (1.0, 60.0));
+        assert_eq!(data.columns[2].lower, -1e30);
+        assert_eq!(data.sos[0].sostype, SOSType::Type2);
+        assert_eq!(data.sos[0].weights, vec![5.0, 10.0, 15.0]);
+
+        let ours = data.to_problem().unwrap();
+        assert_eq!(lp_text(&ours), lp_text(&c_read_lp(MODEL, "c-reader")));
+    }
+
+    #[test]
+    fn round_trip() {
+        let lp = parse(MODEL).unwrap().to_problem().unwrap();
+        let text = LpFile::from_problem(&lp).unwrap().to_string();
+        assert_eq!(lp_text(&parse(&text).unwrap().to_problem().unwrap()), lp_text(&lp));
+        assert_eq!(lp_text(&c_read_lp(&text, "round-trip")), lp_text(&lp));
+        assert_eq!(ModelData::from_problem(&c_read_lp(&lp_text(&lp), "write-lp")),
+                   parse(&lp_text(&lp)).unwrap().to_model_data().unwrap());
+
+        // Numbers are written with every digit they need, unlike lpsolve's 12.
+        let mut data = parse(MODEL).unwrap().to_model_data().unwrap();
+        data.rows[0].coefficients[0].1 = 0.1 + 0.2;
+        data.objective[1].1 = 1.0 / 3.0;
+        let text = LpFile::from_model_data(&data).unwrap().to_string();
+        assert_eq!(parse(&text).unwrap().to_model_data().unwrap(), data);
+    }
+
+    #[test]
+    fn free_keeps_upper_bound() {
+        let data = parse("max: x + y;\nc1: x + y <= 5;\nx <= 4;\nfree x, y;").unwrap().to_model_data().unwrap();
+        assert_eq!((data.columns[0].lower, data.columns[0].upper), (-1e30, 4.0));
+        assert_eq!((data.columns[1].lower, data.columns[1].upper), (-1e30, 1e30));
+        let text = LpFile::from_model_data(&data).unwrap().to_string();
+        assert_eq!(parse(&text).unwrap().to_model_data().unwrap(), data);
+    }
+
+    #[test]
+    fn errors() {
+        let err = |src: &str| {
+            let file = parse(src)?;
+            file.to_model_data().map(|_| ())
+        };
+        let e = err("max: x;\nc1: x + y >= 2\nc2: x <= 3;").unwrap_err();
+        assert_eq!(e.to_string(), "line 3, column 1: expected `;`, found `c2`");
+        let e = err("max: x;\nx + y >= 2;\nc1: <= 4;").unwrap_err();
+        assert_eq!(e.message, "range for undefined row `c1`");
+        assert_eq!((e.span.line, e.span.column, e.span.end - e.span.start), (3, 1, 2));
+        let e = err("max: x;\nx >= 4;\nx <= 3;").unwrap_err();
+        assert_eq!((e.span.line, e.span.column), (3, 1));
+        let e = err("max: x;\nc1: x + y >= 2;\nc1: >= 1;").unwrap_err();
+        assert_eq!((e.span.line, e.span.column), (3, 1));
+        assert!(err("x + y >= 2;").is_err());
+        assert!(err("max: x;\n/* open").is_err());
+    }
+
+    #[test]
+    fn unwritable_names() {
+        let mut lp = parse("max: x + y;\nc1: x + y <= 4;").unwrap().to_problem().unwrap();
+        assert!(lp.set_col_name(1, &CString::new("x[a,1]").unwrap()));
+        let err = LpFile::from_problem(&lp).unwrap_err();
+        assert_eq!(err.message, "`x[a,1]` can't be written as an lp-format name");
+        assert!(lp.set_col_name(1, &CString::new("x[a_1]").unwrap()));
+        assert!(lp.set_row_name(1, &CString::new("int").unwrap()));
+        assert!(LpFile::from_problem(&lp).is_err());
+        assert!(lp.set_row_name(1, &CString::new("c[1]").unwrap()));
+        let text = LpFile::from_problem(&lp).unwrap().to_string();
+        assert_eq!(lp_text(&parse(&text).unwrap().to_problem().unwrap()), lp_text(&lp));
+    }
+
+    #[test]
+    fn negative_upper_bound() {
+        // Like the C reader, the default lower bound of 0 stays, which makes the model infeasible.
+        let text = "max: x + y;\nc1: x + y <= 4;\nx <= -5;\n";
+        let mut ours = parse(text).unwrap().to_problem().unwrap();
+        assert_eq!((ours.get_lower_bound(1), ours.get_upper_bound(1)), (Some(0.0), Some(-5.0)));
+        assert_eq!(lp_text(&ours), lp_text(&c_read_lp(text, "negative-upper-bound")));
+        assert_ne!(ours.solve(), SolveStatus::Optimal);
+
+        let e = parse("max: x;\nx >= 1;\nx <= -5;").unwrap().to_model_data().unwrap_err();
+        assert_eq!((e.span.line, e.span.column), (3, 1));
+    }
+}
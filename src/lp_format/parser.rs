@@ -0,0 +1,331 @@
+//! Recursive descent parser producing the syntax tree.
+
+use libc::c_int;
+
+use {ConstraintType, SOSType};
+use super::lexer::{Lexer, Token};
+use super::{Declaration, Expression, Ident, LpFile, Objective, ParseError, Relation, SosMember, SosSet, Span,
+            Statement, Term};
+
+/// The value of `+inf`, as in lpsolve.
+const INFINITY: f64 = 1e30;
+
+fn describe(token: &Token) -> String {
+    match *token {
+        Token::Number(v) => format!("number `{}`", v),
+        Token::Ident(ref name) => format!("`{}`", name),
+        Token::Inf(true) => "`-inf`".to_string(),
+        Token::Inf(false) => "`+inf`".to_string(),
+        Token::Sign(true) => "`-`".to_string(),
+        Token::Sign(false) => "`+`".to_string(),
+        Token::Minimize => "`min:`".to_string(),
+        Token::Maximize => "`max:`".to_string(),
+        Token::Section(_) | Token::SosSection(_) => "section keyword".to_string(),
+        Token::Le => "`<=`".to_string(),
+        Token::Ge => "`>=`".to_string(),
+        Token::Eq => "`=`".to_string(),
+        Token::Colon => "`:`".to_string(),
+        Token::Comma => "`,`".to_string(),
+        Token::Star => "`*`".to_string(),
+        Token::Semicolon => "`;`".to_string(),
+        Token::Eof => "end of input".to_string(),
+    }
+}
+
+fn join(start: Span, end: Span) -> Span {
+    Span { end: end.end, ..start }
+}
+
+pub struct Parser {
+    tokens: Vec<(Token, Span)>,
+    pos: usize,
+}
+
+impl Parser {
+    pub fn new(src: &str) -> Result<Parser, ParseError> {
+        let mut lexer = Lexer::new(src);
+        let mut tokens = Vec::new();
+        loop {
+            let (token, span) = lexer.next_token()?;
+            let eof = token == Token::Eof;
+            tokens.push((token, span));
+            if eof {
+                return Ok(Parser { tokens, pos: 0 });
+            }
+        }
+    }
+
+    fn peek_at(&self, n: usize) -> &Token {
+        let idx = ::std::cmp::min(self.pos + n, self.tokens.len() - 1);
+        &self.tokens[idx].0
+    }
+
+    fn peek(&self) -> &Token {
+        self.peek_at(0)
+    }
+
+    fn span(&self) -> Span {
+        self.tokens[self.pos].1
+    }
+
+    /// The span of the previous token.
+    fn last_span(&self) -> Span {
+        self.tokens[self.pos.saturating_sub(1)].1
+    }
+
+    fn advance(&mut self) -> (Token, Span) {
+        let token = self.tokens[self.pos].clone();
+        if self.pos + 1 < self.tokens.len() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn unexpected(&self, expected: &str) -> ParseError {
+        ParseError::new(format!("expected {}, found {}", expected, describe(self.peek())), self.span())
+    }
+
+    fn operator(&self) -> Option<ConstraintType> {
+        match *self.peek() {
+            Token::Le => Some(ConstraintType::Le),
+            Token::Ge => Some(ConstraintType::Ge),
+            Token::Eq => Some(ConstraintType::Eq),
+            _ => None,
+        }
+    }
+
+    fn semicolon(&mut self) -> Result<(), ParseError> {
+        if *self.peek() == Token::Semicolon {
+            self.advance();
+            Ok(())
+        } else {
+            Err(self.unexpected("`;`"))
+        }
+    }
+
+    fn ident(&mut self) -> Result<Ident, ParseError> {
+        match self.advance() {
+            (Token::Ident(name), span) => Ok(Ident { name, span }),
+            _ => {
+                self.pos -= 1;
+                Err(self.unexpected("a variable name"))
+            }
+        }
+    }
+
+    /// A label `name:` at the start of a statement.
+    fn label(&mut self) -> Option<Ident> {
+        match (self.peek().clone(), self.peek_at(1)) {
+            (Token::Ident(name), &Token::Colon) => {
+                let span = self.advance().1;
+                self.advance();
+                Some(Ident { name, span })
+            }
+            _ => None,
+        }
+    }
+
+    pub fn file(&mut self) -> Result<LpFile, ParseError> {
+        let objective = self.objective()?;
+        let mut statements = Vec::new();
+        loop {
+            match *self.peek() {
+                Token::Eof => break,
+                Token::Section(_) => statements.push(Statement::Declaration(self.declaration()?)),
+                Token::SosSection(sostype) => {
+                    self.advance();
+                    loop {
+                        match *self.peek() {
+                            Token::Eof | Token::Section(_) | Token::SosSection(_) => break,
+                            _ => statements.push(Statement::Sos(self.sos_set(sostype)?)),
+                        }
+                    }
+                }
+                _ => statements.push(Statement::Relation(self.relation()?)),
+            }
+        }
+        Ok(LpFile { objective, statements })
+    }
+
+    fn objective(&mut self) -> Result<Objective, ParseError> {
+        let start = self.span();
+        let maximize = *self.peek() == Token::Maximize;
+        if maximize || *self.peek() == Token::Minimize {
+            self.advance();
+        }
+        let expression = self.expression()?;
+        if self.operator().is_some() || *self.peek() == Token::Colon {
+            return Err(ParseError::new("the first statement must be the objective function".to_string(), start));
+        }
+        self.semicolon()?;
+        Ok(Objective { maximize, expression, span: join(start, self.last_span()) })
+    }
+
+    fn term(&mut self) -> Result<Option<Term>, ParseError> {
+        let start = self.span();
+        let mut negative = false;
+        let mut signed = false;
+        while let Token::Sign(neg) = *self.peek() {
+            negative ^= neg;
+            signed = true;
+            self.advance();
+        }
+        let sign = if negative { -1.0 } else { 1.0 };
+        let (coefficient, variable) = match self.peek().clone() {
+            Token::Inf(neg) => {
+                self.advance();
+                (if neg { -sign * INFINITY } else { sign * INFINITY }, None)
+            }
+            Token::Number(value) => {
+                self.advance();
+                match *self.peek() {
+                    Token::Star => {
+                        self.advance();
+                        (sign * value, Some(self.ident()?))
+                    }
+                    Token::Ident(_) if *self.peek_at(1) != Token::Colon => (sign * value, Some(self.ident()?)),
+                    _ => (sign * value, None),
+                }
+            }
+            Token::Ident(_) if *self.peek_at(1) != Token::Colon => (sign, Some(self.ident()?)),
+            _ if signed => return Err(self.unexpected("a number or variable")),
+            _ => return Ok(None),
+        };
+        Ok(Some(Term { coefficient, variable, span: join(start, self.last_span()) }))
+    }
+
+    fn expression(&mut self) -> Result<Expression, ParseError> {
+        let start = self.span();
+        let mut terms = Vec::new();
+        while let Some(term) = self.term()? {
+            terms.push(term);
+        }
+        let span = if terms.is_empty() { Span { end: start.start, ..start } } else { join(start, self.last_span()) };
+        Ok(Expression { terms, span })
+    }
+
+    fn relation(&mut self) -> Result<Relation, ParseError> {
+        let start = self.span();
+        let name = self.label();
+        let lhs = self.expression()?;
+        let op = match self.operator() {
+            Some(op) => op,
+            None => return Err(self.unexpected("a relational operator")),
+        };
+        self.advance();
+        let rhs = self.expression()?;
+        let range = match self.operator() {
+            Some(op) => {
+                self.advance();
+                Some((op, self.expression()?))
+            }
+            None => None,
+        };
+        self.semicolon()?;
+        Ok(Relation { name, lhs, op, rhs, range, span: join(start, self.last_span()) })
+    }
+
+    fn declaration(&mut self) -> Result<Declaration, ParseError> {
+        let (token, start) = self.advance();
+        let kind = match token {
+            Token::Section(kind) => kind,
+            _ => unreachable!(),
+        };
+        let mut variables = vec![self.ident()?];
+        while *self.peek() != Token::Semicolon {
+            if *self.peek() == Token::Comma {
+                self.advance();
+            }
+            variables.push(self.ident()?);
+        }
+        self.semicolon()?;
+        Ok(Declaration { kind, variables, span: join(start, self.last_span()) })
+    }
+
+    fn integer(&mut self) -> Result<c_int, ParseError> {
+        match *self.peek() {
+            Token::Number(v) if v.fract() == 0.0 && v.abs() <= c_int::MAX as f64 => {
+                self.advance();
+                Ok(v as c_int)
+            }
+            _ => Err(self.unexpected("an integer")),
+        }
+    }
+
+    fn weight(&mut self) -> Result<f64, ParseError> {
+        let mut sign = 1.0;
+        while let Token::Sign(neg) = *self.peek() {
+            if neg {
+                sign = -sign;
+            }
+            self.advance();
+        }
+        match *self.peek() {
+            Token::Number(v) => {
+                self.advance();
+                Ok(sign * v)
+            }
+            _ => Err(self.unexpected("a weight")),
+        }
+    }
+
+    /// A set in a `sos1`, `sos2` or generic (`sostype == 0`) `sos` section.
+    fn sos_set(&mut self, sostype: i32) -> Result<SosSet, ParseError> {
+        let start = self.span();
+        let name = match (self.peek_at(1), self.peek_at(2)) {
+            (&Token::Colon, &Token::Ident(_)) => self.label(),
+            _ => None,
+        };
+        let mut members = Vec::new();
+        loop {
+            let variable = self.ident()?;
+            let weight = if *self.peek() == Token::Colon {
+                self.advance();
+                Some(self.weight()?)
+            } else {
+                None
+            };
+            members.push(SosMember { variable, weight });
+            if *self.peek() == Token::Comma {
+                self.advance();
+            }
+            if *self.peek() == Token::Le || *self.peek() == Token::Semicolon {
+                break;
+            }
+        }
+
+        let mut numbers = Vec::new();
+        if *self.peek() == Token::Le {
+            if sostype != 0 {
+                return Err(ParseError::new("the type of a set in a `sos1` or `sos2` section can't be given"
+                                           .to_string(), self.span()));
+            }
+            self.advance();
+            numbers.push((self.span(), self.integer()?));
+            if *self.peek() == Token::Colon {
+                self.advance();
+                numbers.push((self.span(), self.integer()?));
+            }
+        }
+        self.semicolon()?;
+
+        let (sostype, priority) = if sostype == 0 {
+            let (span, value) = match numbers.first() {
+                Some(&n) => n,
+                None => {
+                    return Err(ParseError::new("a set in a `sos` section needs a type, as in `<= 2`".to_string(),
+                                               self.last_span()))
+                }
+            };
+            let kind = match value {
+                1 => SOSType::Type1,
+                2 => SOSType::Type2,
+                _ => return Err(ParseError::new(format!("unsupported SOS type {}", value), span)),
+            };
+            (kind, numbers.get(1).map(|&(_, p)| p))
+        } else {
+            (if sostype == 1 { SOSType::Type1 } else { SOSType::Type2 }, None)
+        };
+        Ok(SosSet { name, sostype, priority, members, span: join(start, self.last_span()) })
+    }
+}
@@ -0,0 +1,343 @@
+//! Printing syntax trees as lp-format text, and building them from a `ModelData`.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use libc::c_int;
+
+use {ConstraintType, ModelData};
+use super::{Declaration, DeclarationKind, Expression, Ident, LpFile, Objective, ParseError, Relation, SosMember, SosSet,
+            Span, Statement, Term};
+use super::lexer::is_name;
+
+const INFINITY: f64 = 1e30;
+
+/// Formats `value` with the fewest digits that read back as the same number, switching to an
+/// exponent like C's `%g` for very large and very small values.
+pub fn format_number(value: f64) -> String {
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    let sci = format!("{:e}", value);
+    let e = sci.find('e').expect("exponent");
+    let exp: i32 = sci[e + 1..].parse().expect("exponent");
+    if !(-4..16).contains(&exp) {
+        format!("{}e{}{:02}", &sci[..e], if exp < 0 { '-' } else { '+' }, exp.abs())
+    } else {
+        value.to_string()
+    }
+}
+
+/// Formats a constant, writing values beyond `1e30` in magnitude as `+Inf` and `-Inf`.
+fn format_constant(value: f64, signed: bool) -> String {
+    if value >= INFINITY {
+        "+Inf".to_string()
+    } else if value <= -INFINITY {
+        "-Inf".to_string()
+    } else if signed && value >= 0.0 {
+        format!("+{}", format_number(value))
+    } else {
+        format_number(value)
+    }
+}
+
+fn operator(op: ConstraintType) -> &'static str {
+    match op {
+        ConstraintType::Le => "<=",
+        ConstraintType::Eq => "=",
+        ConstraintType::Ge | ConstraintType::Free => ">=",
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.variable {
+            Some(ref var) => match format_constant(self.coefficient, true).as_str() {
+                "+1" => write!(f, "+{}", var.name),
+                "-1" => write!(f, "-{}", var.name),
+                c => write!(f, "{} {}", c, var.name),
+            },
+            None => f.write_str(&format_constant(self.coefficient, true)),
+        }
+    }
+}
+
+impl fmt::Display for Expression {
+    /// A lone constant is written without a sign, like a right hand side.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let [Term { variable: None, coefficient, .. }] = self.terms[..] {
+            return f.write_str(&format_constant(coefficient, false));
+        }
+        for (i, term) in self.terms.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", term)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Relation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(ref name) = self.name {
+            write!(f, "{}: ", name.name)?;
+        }
+        if !self.lhs.terms.is_empty() {
+            write!(f, "{} ", self.lhs)?;
+        }
+        write!(f, "{} {}", operator(self.op), self.rhs)?;
+        if let Some((op, ref last)) = self.range {
+            write!(f, " {} {}", operator(op), last)?;
+        }
+        f.write_str(";")
+    }
+}
+
+impl fmt::Display for Declaration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self.kind {
+            DeclarationKind::Int => "int",
+            DeclarationKind::Bin => "bin",
+            DeclarationKind::Sec => "sec",
+            DeclarationKind::Sin => "sin",
+            DeclarationKind::Free => "free",
+        })?;
+        for (i, var) in self.variables.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { " " } else { "," }, var.name)?;
+        }
+        f.write_str(";")
+    }
+}
+
+/// Writes `set` for a generic `sos` section, naming it `SOS{index}` if it has no name.
+fn write_sos(f: &mut fmt::Formatter, set: &SosSet, index: usize) -> fmt::Result {
+    match set.name {
+        Some(ref name) => write!(f, "{}: ", name.name)?,
+        None => write!(f, "SOS{}: ", index)?,
+    }
+    for (i, member) in set.members.iter().enumerate() {
+        if i > 0 {
+            f.write_str(",")?;
+        }
+        f.write_str(&member.variable.name)?;
+        if let Some(weight) = member.weight {
+            write!(f, ":{}", format_number(weight))?;
+        }
+    }
+    write!(f, " <= {}", set.sostype as c_int)?;
+    if let Some(priority) = set.priority {
+        write!(f, ":{}", priority)?;
+    }
+    f.write_str(";\n")
+}
+
+/// Writes the file in the style of lpsolve's `write_lp`.
+///
+/// An `sos` section only ends at another section keyword, so the SOS sets are written after all
+/// other statements.
+pub fn write(file: &LpFile, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str("/* Objective function */\n")?;
+    f.write_str(if file.objective.maximize { "max: " } else { "min: " })?;
+    for (i, term) in file.objective.expression.terms.iter().enumerate() {
+        write!(f, "{}{}", if i == 0 { "" } else { " " }, term)?;
+    }
+    f.write_str(";\n")?;
+
+    let mut previous = None;
+    for statement in &file.statements {
+        match *statement {
+            Statement::Relation(ref rel) => {
+                if previous != Some(0) {
+                    f.write_str("\n/* Constraints */\n")?;
+                    previous = Some(0);
+                }
+                writeln!(f, "{}", rel)?;
+            }
+            Statement::Declaration(ref decl) => {
+                if previous != Some(1) {
+                    f.write_str("\n")?;
+                    previous = Some(1);
+                }
+                writeln!(f, "{}", decl)?;
+            }
+            Statement::Sos(_) => {}
+        }
+    }
+
+    let sets = file.statements.iter().filter_map(|s| match *s {
+        Statement::Sos(ref set) => Some(set),
+        _ => None,
+    });
+    for (i, set) in sets.enumerate() {
+        if i == 0 {
+            f.write_str("\n/* SOS definitions */\nsos\n")?;
+        }
+        write_sos(f, set, i + 1)?;
+    }
+    Ok(())
+}
+
+fn constant(value: f64) -> Expression {
+    let term = Term { coefficient: value.clamp(-INFINITY, INFINITY), variable: None, span: Span::default() };
+    Expression { terms: vec![term], span: Span::default() }
+}
+
+fn relation(name: Option<Ident>, lhs: Expression, op: ConstraintType, rhs: Expression,
+            range: Option<(ConstraintType, Expression)>) -> Statement {
+    Statement::Relation(Relation { name, lhs, op, rhs, range, span: Span::default() })
+}
+
+/// Checks that `name` can be written and read back unchanged.
+fn ident(name: &str) -> Result<Ident, ParseError> {
+    if is_name(name) {
+        Ok(Ident::new(name))
+    } else {
+        Err(ParseError::new(format!("`{}` can't be written as an lp-format name", name), Span::default()))
+    }
+}
+
+pub fn from_model_data(data: &ModelData) -> Result<LpFile, ParseError> {
+    let names: Vec<String> = data.columns.iter().enumerate()
+        .map(|(j, col)| col.name.clone().unwrap_or_else(|| format!("C{}", j + 1)))
+        .collect();
+    for name in &names {
+        ident(name)?;
+    }
+    let expression = |coefficients: &[(c_int, f64)]| Expression {
+        terms: coefficients.iter().map(|&(col, value)| Term {
+            coefficient: value,
+            variable: Some(Ident::new(names[col as usize - 1].as_str())),
+            span: Span::default(),
+        }).collect(),
+        span: Span::default(),
+    };
+
+    // The reader numbers columns in order of first appearance. If that order would differ from
+    // the model's, list every column in the objective to fix it.
+    let mut seen = HashSet::new();
+    let mut order = Vec::new();
+    for &(col, _) in data.objective.iter().chain(data.rows.iter().flat_map(|r| r.coefficients.iter())) {
+        if seen.insert(col) {
+            order.push(col);
+        }
+    }
+    let in_order = order.len() == data.columns.len()
+        && order.iter().enumerate().all(|(i, &col)| col as usize == i + 1);
+    let mut objective = if in_order {
+        expression(&data.objective)
+    } else {
+        let mut dense = vec![0.0; data.columns.len()];
+        for &(col, value) in &data.objective {
+            dense[col as usize - 1] = value;
+        }
+        let all: Vec<(c_int, f64)> = dense.into_iter().enumerate().map(|(j, v)| (j as c_int + 1, v)).collect();
+        expression(&all)
+    };
+    if data.objective_constant != 0.0 {
+        objective.terms.push(constant(data.objective_constant).terms.remove(0));
+    }
+
+    let mut statements = Vec::new();
+    for (i, row) in data.rows.iter().enumerate() {
+        let mut terms = expression(&row.coefficients);
+        if terms.terms.is_empty() && !names.is_empty() {
+            terms = expression(&[(1, 0.0)]);
+        }
+        // A row with a single variable needs a label, or it would be read as a bound.
+        let name = match row.name {
+            Some(ref name) => Some(ident(name)?),
+            None if terms.terms.len() <= 1 => Some(Ident::new(format!("R{}", i + 1))),
+            None => None,
+        };
+        let (kind, rhs) = match row.kind {
+            ConstraintType::Free => (ConstraintType::Ge, -INFINITY),
+            kind => (kind, row.rhs),
+        };
+        statements.push(match row.range {
+            // The first operator of a double inequality decides the type of the row, so this is
+            // the reverse of the order `write_lp` uses.
+            Some(range) if range < data.infinity && kind == ConstraintType::Le => {
+                let op = ConstraintType::Ge;
+                relation(name, constant(rhs), op, terms, Some((op, constant(rhs - range))))
+            }
+            Some(range) if range < data.infinity && kind == ConstraintType::Ge => {
+                let op = ConstraintType::Le;
+                relation(name, constant(rhs), op, terms, Some((op, constant(rhs + range))))
+            }
+            _ => relation(name, terms, kind, constant(rhs), None),
+        });
+    }
+
+    for (j, col) in data.columns.iter().enumerate() {
+        let var = || expression(&[(j as c_int + 1, 1.0)]);
+        let infinite = col.upper >= data.infinity;
+        if col.lower == col.upper {
+            statements.push(relation(None, var(), ConstraintType::Eq, constant(col.upper), None));
+        } else if col.lower != 0.0 && !infinite {
+            statements.push(relation(None, constant(col.lower), ConstraintType::Le, var(),
+                                     Some((ConstraintType::Le, constant(col.upper)))));
+        } else {
+            if col.lower != 0.0 {
+                statements.push(relation(None, var(), ConstraintType::Ge, constant(col.lower), None));
+            }
+            if !infinite {
+                statements.push(relation(None, var(), ConstraintType::Le, constant(col.upper), None));
+            }
+        }
+    }
+
+    for &(kind, integer) in &[(DeclarationKind::Int, true), (DeclarationKind::Sec, false)] {
+        let variables: Vec<Ident> = data.columns.iter().zip(&names)
+            .filter(|&(col, _)| if integer { col.integer } else { col.semicontinuous })
+            .map(|(_, name)| Ident::new(name.as_str()))
+            .collect();
+        if !variables.is_empty() {
+            statements.push(Statement::Declaration(Declaration { kind, variables, span: Span::default() }));
+        }
+    }
+
+    let mut priority = 0;
+    for set in &data.sos {
+        let mut weight = 0.0;
+        let members = set.variables.iter().zip(&set.weights).map(|(&col, &w)| {
+            let member = SosMember {
+                variable: Ident::new(names[col as usize - 1].as_str()),
+                weight: if w == weight + 1.0 { None } else { Some(w) },
+            };
+            weight = w;
+            member
+        }).collect();
+        statements.push(Statement::Sos(SosSet {
+            name: if set.name.is_empty() { None } else { Some(ident(&set.name)?) },
+            sostype: set.sostype,
+            priority: if set.priority == priority + 1 { None } else { Some(set.priority) },
+            members,
+            span: Span::default(),
+        }));
+        priority = set.priority;
+    }
+
+    Ok(LpFile {
+        objective: Objective { maximize: data.maximize, expression: objective, span: Span::default() },
+        statements,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_number;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(format_number(143.0), "143");
+        assert_eq!(format_number(-0.5), "-0.5");
+        assert_eq!(format_number(1.0 / 3.0), "0.3333333333333333");
+        assert_eq!(format_number(0.1 + 0.2), "0.30000000000000004");
+        assert_eq!(format_number(1e30), "1e+30");
+        assert_eq!(format_number(-2.5e-7), "-2.5e-07");
+        assert_eq!(format_number(0.00001), "1e-05");
+        assert_eq!(format_number(123456789012.0), "123456789012");
+        assert_eq!(format_number(1e16), "1e+16");
+    }
+}
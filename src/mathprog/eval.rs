@@ -0,0 +1,542 @@
+//! Instantiating a model: expanding indexed declarations over their domains with the data.
+
+use std::collections::{BTreeMap, HashMap};
+
+use libc::c_int;
+
+use {ColumnData, ConstraintType, ModelData, RowData};
+use lp_format::{Ident, ParseError, Span};
+use super::parser::{BinOp, CmpOp, Cond, Data, Domain, Expr, ExprKind, SetExpr, Statement};
+use super::Value;
+
+const INFINITY: f64 = 1e30;
+
+/// The most members a range like `1..n` may have.
+const MAX_RANGE: f64 = 1e7;
+
+/// The most members a domain may have, counting those its condition drops.
+const MAX_MEMBERS: usize = 10_000_000;
+
+fn error<T>(message: String, span: Span) -> Result<T, ParseError> {
+    Err(ParseError::new(message, span))
+}
+
+/// A linear expression over the columns built so far.
+#[derive(Debug, Clone, Default)]
+struct Linear {
+    terms: BTreeMap<c_int, f64>,
+    constant: f64,
+}
+
+impl Linear {
+    fn constant(value: f64) -> Linear {
+        Linear { terms: BTreeMap::new(), constant: value }
+    }
+
+    fn add(mut self, other: &Linear, factor: f64) -> Linear {
+        for (&col, &value) in &other.terms {
+            *self.terms.entry(col).or_insert(0.0) += factor * value;
+        }
+        self.constant += factor * other.constant;
+        self
+    }
+
+    fn scale(mut self, factor: f64) -> Linear {
+        for value in self.terms.values_mut() {
+            *value *= factor;
+        }
+        self.constant *= factor;
+        self
+    }
+
+    fn coefficients(&self) -> Vec<(c_int, f64)> {
+        self.terms.iter().filter(|&(_, &v)| v != 0.0).map(|(&col, &v)| (col, v)).collect()
+    }
+}
+
+/// The result of evaluating an expression.
+#[derive(Debug, Clone)]
+enum Val {
+    Sym(String),
+    Lin(Linear),
+}
+
+struct Param {
+    dim: usize,
+    values: HashMap<Vec<Value>, Value>,
+    default: Option<Value>,
+}
+
+struct Var {
+    dim: usize,
+    columns: HashMap<Vec<Value>, c_int>,
+}
+
+enum Entity {
+    Set(Vec<Value>),
+    Param(Param),
+    Var(Var),
+    Objective,
+    Constraint,
+}
+
+struct Env<'a> {
+    data: &'a Data,
+    entities: HashMap<String, Entity>,
+    dummies: Vec<(String, Value)>,
+    model: ModelData,
+    has_objective: bool,
+}
+
+/// Formats `name[a,b]`, or `name` without subscripts.
+fn member_name(name: &str, key: &[Value]) -> String {
+    if key.is_empty() {
+        return name.to_string();
+    }
+    let subscripts: Vec<String> = key.iter().map(|v| v.to_string()).collect();
+    format!("{}[{}]", name, subscripts.join(","))
+}
+
+fn compare(lhs: &Value, op: CmpOp, rhs: &Value) -> bool {
+    let ordering = lhs.partial_cmp(rhs);
+    match op {
+        CmpOp::Lt => ordering == Some(::std::cmp::Ordering::Less),
+        CmpOp::Le => lhs == rhs || ordering == Some(::std::cmp::Ordering::Less),
+        CmpOp::Eq => lhs == rhs,
+        CmpOp::Ne => lhs != rhs,
+        CmpOp::Ge => lhs == rhs || ordering == Some(::std::cmp::Ordering::Greater),
+        CmpOp::Gt => ordering == Some(::std::cmp::Ordering::Greater),
+    }
+}
+
+impl<'a> Env<'a> {
+    fn declare(&mut self, name: &Ident, entity: Entity) -> Result<(), ParseError> {
+        if self.entities.contains_key(&name.name) {
+            return error(format!("`{}` is declared twice", name.name), name.span);
+        }
+        self.entities.insert(name.name.clone(), entity);
+        Ok(())
+    }
+
+    fn eval(&mut self, expr: &Expr) -> Result<Val, ParseError> {
+        Ok(match expr.kind {
+            ExprKind::Number(value) => Val::Lin(Linear::constant(value)),
+            ExprKind::Str(ref s) => Val::Sym(s.clone()),
+            ExprKind::Ref(ref name, ref subscripts) => return self.reference(name, subscripts, expr.span),
+            ExprKind::Neg(ref operand) => Val::Lin(self.linear(operand)?.scale(-1.0)),
+            ExprKind::Binary(op, ref lhs, ref rhs) => {
+                let (a, b) = (self.linear(lhs)?, self.linear(rhs)?);
+                Val::Lin(match op {
+                    BinOp::Add => a.add(&b, 1.0),
+                    BinOp::Sub => a.add(&b, -1.0),
+                    BinOp::Mul if b.terms.is_empty() => a.scale(b.constant),
+                    BinOp::Mul if a.terms.is_empty() => b.scale(a.constant),
+                    BinOp::Mul => return error("the product of two variables is not linear".to_string(), expr.span),
+                    BinOp::Div if !b.terms.is_empty() => {
+                        return error("division by a variable is not linear".to_string(), expr.span)
+                    }
+                    BinOp::Div if b.constant == 0.0 => return error("division by zero".to_string(), expr.span),
+                    BinOp::Div => a.scale(1.0 / b.constant),
+                })
+            }
+            ExprKind::Sum(ref domain, ref body) => {
+                let mut total = Linear::default();
+                for key in self.expand(domain)? {
+                    let depth = self.bind(domain, &key);
+                    let term = self.linear(body);
+                    self.dummies.truncate(depth);
+                    total = total.add(&term?, 1.0);
+                }
+                Val::Lin(total)
+            }
+        })
+    }
+
+    fn linear(&mut self, expr: &Expr) -> Result<Linear, ParseError> {
+        match self.eval(expr)? {
+            Val::Lin(lin) => Ok(lin),
+            Val::Sym(s) => error(format!("expected a number, found the symbol `{}`", s), expr.span),
+        }
+    }
+
+    fn number(&mut self, expr: &Expr) -> Result<f64, ParseError> {
+        let lin = self.linear(expr)?;
+        if lin.terms.is_empty() {
+            Ok(lin.constant)
+        } else {
+            error("expected a constant, found an expression with variables".to_string(), expr.span)
+        }
+    }
+
+    fn value(&mut self, expr: &Expr) -> Result<Value, ParseError> {
+        match self.eval(expr)? {
+            Val::Sym(s) => Ok(Value::Sym(s)),
+            Val::Lin(ref lin) if lin.terms.is_empty() => Ok(Value::Num(lin.constant)),
+            Val::Lin(_) => error("expected a constant, found an expression with variables".to_string(), expr.span),
+        }
+    }
+
+    fn reference(&mut self, name: &Ident, subscripts: &[Expr], span: Span) -> Result<Val, ParseError> {
+        if subscripts.is_empty() {
+            if let Some((_, value)) = self.dummies.iter().rev().find(|&(d, _)| *d == name.name) {
+                return Ok(match *value {
+                    Value::Num(v) => Val::Lin(Linear::constant(v)),
+                    Value::Sym(ref s) => Val::Sym(s.clone()),
+                });
+            }
+        }
+        let mut key = Vec::new();
+        for subscript in subscripts {
+            key.push(self.value(subscript)?);
+        }
+        let wrong_count = |dim: usize| {
+            error(format!("`{}` takes {} subscripts, found {}", name.name, dim, key.len()), span)
+        };
+        match self.entities.get(&name.name) {
+            Some(Entity::Param(param)) => {
+                if param.dim != key.len() {
+                    return wrong_count(param.dim);
+                }
+                match param.values.get(&key).or(param.default.as_ref()) {
+                    Some(&Value::Num(v)) => Ok(Val::Lin(Linear::constant(v))),
+                    Some(Value::Sym(s)) => Ok(Val::Sym(s.clone())),
+                    None => error(format!("no value for `{}`", member_name(&name.name, &key)), span),
+                }
+            }
+            Some(Entity::Var(var)) => {
+                if var.dim != key.len() {
+                    return wrong_count(var.dim);
+                }
+                match var.columns.get(&key) {
+                    Some(&col) => {
+                        let mut lin = Linear::default();
+                        lin.terms.insert(col, 1.0);
+                        Ok(Val::Lin(lin))
+                    }
+                    None => error(format!("`{}` is out of the domain", member_name(&name.name, &key)), span),
+                }
+            }
+            Some(&Entity::Set(_)) => error(format!("`{}` is a set, not a value", name.name), span),
+            Some(_) => error(format!("`{}` can't be used in an expression", name.name), span),
+            None => error(format!("`{}` is not declared", name.name), name.span),
+        }
+    }
+
+    fn condition(&mut self, cond: &Cond) -> Result<bool, ParseError> {
+        Ok(match *cond {
+            Cond::Compare(ref lhs, op, ref rhs) => {
+                let (a, b) = (self.value(lhs)?, self.value(rhs)?);
+                compare(&a, op, &b)
+            }
+            Cond::And(ref a, ref b) => self.condition(a)? && self.condition(b)?,
+            Cond::Or(ref a, ref b) => self.condition(a)? || self.condition(b)?,
+            Cond::Not(ref a) => !self.condition(a)?,
+        })
+    }
+
+    fn set(&mut self, set: &SetExpr) -> Result<Vec<Value>, ParseError> {
+        match *set {
+            SetExpr::Named(ref name) => match self.entities.get(&name.name) {
+                Some(Entity::Set(values)) => Ok(values.clone()),
+                Some(_) => error(format!("`{}` is not a set", name.name), name.span),
+                None => error(format!("`{}` is not declared", name.name), name.span),
+            },
+            SetExpr::Range(ref from_expr, ref to_expr) => {
+                let (from, to) = (self.number(from_expr)?, self.number(to_expr)?);
+                let span = Span { end: to_expr.span.end, ..from_expr.span };
+                if !from.is_finite() || !to.is_finite() {
+                    return error("the range is not finite".to_string(), span);
+                }
+                if to - from >= MAX_RANGE {
+                    return error(format!("the range has more than {} members", MAX_RANGE), span);
+                }
+                let mut values = Vec::new();
+                let mut value = from;
+                while value <= to {
+                    values.push(Value::Num(value));
+                    value += 1.0;
+                }
+                Ok(values)
+            }
+            SetExpr::List(ref items) => {
+                let mut values: Vec<Value> = Vec::new();
+                for item in items {
+                    let value = self.value(item)?;
+                    if !values.contains(&value) {
+                        values.push(value);
+                    }
+                }
+                Ok(values)
+            }
+        }
+    }
+
+    /// Binds the dummy indices of `domain` to `key`, returning the depth to truncate to afterwards.
+    fn bind(&mut self, domain: &Domain, key: &[Value]) -> usize {
+        let depth = self.dummies.len();
+        for ((dummy, _), value) in domain.entries.iter().zip(key) {
+            if let Some(ref dummy) = *dummy {
+                self.dummies.push((dummy.name.clone(), value.clone()));
+            }
+        }
+        depth
+    }
+
+    /// Lists the members of `domain` in order. Later sets and the condition may refer to the dummy
+    /// indices of earlier entries.
+    fn expand(&mut self, domain: &Domain) -> Result<Vec<Vec<Value>>, ParseError> {
+        let mut out = Vec::new();
+        let depth = self.dummies.len();
+        let result = self.expand_from(domain, 0, &mut Vec::new(), &mut 0, &mut out);
+        self.dummies.truncate(depth);
+        result.map(|_| out)
+    }
+
+    fn expand_from(&mut self, domain: &Domain, index: usize, key: &mut Vec<Value>, seen: &mut usize,
+                   out: &mut Vec<Vec<Value>>) -> Result<(), ParseError> {
+        if index == domain.entries.len() {
+            *seen += 1;
+            if *seen > MAX_MEMBERS {
+                return error(format!("the domain has more than {} members", MAX_MEMBERS), domain.span);
+            }
+            let keep = match domain.condition {
+                Some(ref cond) => self.condition(cond)?,
+                None => true,
+            };
+            if keep {
+                out.push(key.clone());
+            }
+            return Ok(());
+        }
+        let (ref dummy, ref set) = domain.entries[index];
+        for value in self.set(set)? {
+            key.push(value.clone());
+            if let Some(ref dummy) = *dummy {
+                self.dummies.push((dummy.name.clone(), value));
+            }
+            self.expand_from(domain, index + 1, key, seen, out)?;
+            if dummy.is_some() {
+                self.dummies.pop();
+            }
+            key.pop();
+        }
+        Ok(())
+    }
+
+    /// The members of an optional domain; a scalar has a single empty key.
+    fn members(&mut self, domain: &Option<Domain>) -> Result<Vec<Vec<Value>>, ParseError> {
+        match *domain {
+            Some(ref domain) => self.expand(domain),
+            None => Ok(vec![Vec::new()]),
+        }
+    }
+
+    fn statement(&mut self, statement: &Statement) -> Result<(), ParseError> {
+        match *statement {
+            Statement::Set { ref name, ref value } => {
+                let values = match (self.data.sets.get(&name.name), value) {
+                    (Some((values, _)), _) => values.clone(),
+                    (None, Some(set)) => self.set(set)?,
+                    (None, &None) => return error(format!("no data for set `{}`", name.name), name.span),
+                };
+                let mut unique: Vec<Value> = Vec::new();
+                for value in values {
+                    if !unique.contains(&value) {
+                        unique.push(value);
+                    }
+                }
+                self.declare(name, Entity::Set(unique))
+            }
+            Statement::Param { ref name, ref domain, ref checks, ref default, ref value } => {
+                self.param(name, domain, checks, default, value)
+            }
+            Statement::Var { ref name, ref domain, ref bounds, integer, binary } => {
+                let mut var = Var { dim: domain.as_ref().map_or(0, |d| d.entries.len()), columns: HashMap::new() };
+                for key in self.members(domain)? {
+                    let depth = domain.as_ref().map_or(self.dummies.len(), |d| self.bind(d, &key));
+                    let mut column = ColumnData {
+                        name: Some(member_name(&name.name, &key)),
+                        lower: -INFINITY,
+                        upper: INFINITY,
+                        integer: integer || binary,
+                        semicontinuous: false,
+                    };
+                    if binary {
+                        column.lower = 0.0;
+                        column.upper = 1.0;
+                    }
+                    for &(op, ref expr) in bounds {
+                        let value = self.number(expr)?.clamp(-INFINITY, INFINITY);
+                        if op != CmpOp::Le {
+                            column.lower = value;
+                        }
+                        if op != CmpOp::Ge {
+                            column.upper = value;
+                        }
+                    }
+                    self.dummies.truncate(depth);
+                    self.model.columns.push(column);
+                    var.columns.insert(key, self.model.columns.len() as c_int);
+                }
+                self.declare(name, Entity::Var(var))
+            }
+            Statement::Objective { ref name, maximize, ref expr } => {
+                if self.has_objective {
+                    return error("only one objective is supported".to_string(), name.span);
+                }
+                let lin = self.linear(expr)?;
+                self.model.maximize = maximize;
+                self.model.objective = lin.coefficients();
+                self.model.objective_constant = lin.constant;
+                self.has_objective = true;
+                self.declare(name, Entity::Objective)
+            }
+            Statement::Constraint { ref name, ref domain, ref lhs, op, ref rhs, ref range } => {
+                for key in self.members(domain)? {
+                    let depth = domain.as_ref().map_or(self.dummies.len(), |d| self.bind(d, &key));
+                    let row = self.row(lhs, op, rhs, range);
+                    self.dummies.truncate(depth);
+                    let mut row = row?;
+                    row.name = Some(member_name(&name.name, &key));
+                    self.model.rows.push(row);
+                }
+                self.declare(name, Entity::Constraint)
+            }
+        }
+    }
+
+    fn param(&mut self, name: &Ident, domain: &Option<Domain>, checks: &[(CmpOp, Expr)], default: &Option<Expr>,
+             value: &Option<Expr>) -> Result<(), ParseError> {
+        let dim = domain.as_ref().map_or(0, |d| d.entries.len());
+        let mut param = Param { dim, values: HashMap::new(), default: None };
+        if let Some(ref expr) = *value {
+            for key in self.members(domain)? {
+                let depth = domain.as_ref().map_or(self.dummies.len(), |d| self.bind(d, &key));
+                let value = self.value(expr);
+                self.dummies.truncate(depth);
+                param.values.insert(key, value?);
+            }
+        } else if let Some(data) = self.data.params.get(&name.name) {
+            param.default = data.default.clone();
+            match data.columns {
+                Some(ref columns) => {
+                    if dim != 2 {
+                        return error(format!("a table needs a parameter with 2 subscripts, `{}` has {}", name.name,
+                                             dim), data.span);
+                    }
+                    for row in data.entries.chunks(columns.len() + 1) {
+                        let label = match row[0] {
+                            Some(ref label) if row.len() == columns.len() + 1 => label,
+                            _ => return error(format!("malformed table for `{}`", name.name), data.span),
+                        };
+                        for (column, value) in columns.iter().zip(&row[1..]) {
+                            if let Some(ref value) = *value {
+                                param.values.insert(vec![label.clone(), column.clone()], value.clone());
+                            }
+                        }
+                    }
+                }
+                None => {
+                    if data.entries.len() % (dim + 1) != 0 {
+                        return error(format!("expected {} subscripts and a value for each entry of `{}`", dim,
+                                             name.name), data.span);
+                    }
+                    for entry in data.entries.chunks(dim + 1) {
+                        let key: Option<Vec<Value>> = entry[..dim].iter().cloned().collect();
+                        match (key, &entry[dim]) {
+                            (Some(key), Some(value)) => {
+                                param.values.insert(key, value.clone());
+                            }
+                            (Some(_), &None) => {}
+                            (None, _) => return error(format!("missing subscript for `{}`", name.name), data.span),
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(ref expr) = *default {
+            if param.default.is_none() {
+                param.default = Some(self.value(expr)?);
+            }
+        }
+
+        let keys: Vec<Vec<Value>> = param.values.keys().cloned().collect();
+        for key in keys {
+            let depth = domain.as_ref().map_or(self.dummies.len(), |d| self.bind(d, &key));
+            let failed = self.failed_check(&param.values[&key], checks);
+            self.dummies.truncate(depth);
+            if let Some(span) = failed? {
+                return error(format!("`{}` = {} fails a check", member_name(&name.name, &key), param.values[&key]),
+                             span);
+            }
+        }
+        self.declare(name, Entity::Param(param))
+    }
+
+    /// Returns the span of the first check that `value` fails.
+    fn failed_check(&mut self, value: &Value, checks: &[(CmpOp, Expr)]) -> Result<Option<Span>, ParseError> {
+        for &(op, ref expr) in checks {
+            if !compare(value, op, &self.value(expr)?) {
+                return Ok(Some(expr.span));
+            }
+        }
+        Ok(None)
+    }
+
+    fn row(&mut self, lhs: &Expr, op: CmpOp, rhs: &Expr, range: &Option<(CmpOp, Expr)>)
+           -> Result<RowData, ParseError> {
+        let kind = |op| match op {
+            CmpOp::Le => ConstraintType::Le,
+            CmpOp::Ge => ConstraintType::Ge,
+            _ => ConstraintType::Eq,
+        };
+        let (lhs_lin, rhs_lin) = (self.linear(lhs)?, self.linear(rhs)?);
+        match *range {
+            None => {
+                let lin = lhs_lin.add(&rhs_lin, -1.0);
+                Ok(RowData { name: None, coefficients: lin.coefficients(), kind: kind(op), rhs: -lin.constant,
+                             range: None })
+            }
+            Some((op2, ref last)) => {
+                if op != op2 || op == CmpOp::Eq {
+                    return error("both operators of a double inequality must be `<=` or both `>=`".to_string(),
+                                 last.span);
+                }
+                if !lhs_lin.terms.is_empty() {
+                    return error("a double inequality must start with a constant".to_string(), lhs.span);
+                }
+                let last = self.number(last)?;
+                let (first, last) = (lhs_lin.constant - rhs_lin.constant, last - rhs_lin.constant);
+                let width = if op == CmpOp::Le { last - first } else { first - last };
+                if width < 0.0 {
+                    return error("the range of a double inequality is empty".to_string(), rhs.span);
+                }
+                Ok(RowData { name: None, coefficients: rhs_lin.coefficients(), kind: kind(op), rhs: last,
+                             range: if first.abs() >= INFINITY { None } else { Some(width) } })
+            }
+        }
+    }
+}
+
+pub fn instantiate(statements: &[Statement], data: &Data) -> Result<ModelData, ParseError> {
+    let mut env = Env {
+        data,
+        entities: HashMap::new(),
+        dummies: Vec::new(),
+        model: ModelData {
+            name: String::new(),
+            maximize: false,
+            objective: Vec::new(),
+            objective_constant: 0.0,
+            infinity: INFINITY,
+            rows: Vec::new(),
+            columns: Vec::new(),
+            sos: Vec::new(),
+        },
+        has_objective: false,
+    };
+    for statement in statements {
+        env.statement(statement)?;
+    }
+    Ok(env.model)
+}
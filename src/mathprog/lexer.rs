@@ -0,0 +1,210 @@
+//! Tokenizer for MathProg.
+//!
+//! The model and data sections have different rules for what makes up a word, so the tokenizer
+//! switches to data mode after a `data;` statement. In data mode a symbol may contain `+`, `-` and
+//! `.`, as in `San-Diego`, and words that parse as numbers are numbers.
+
+use lp_format::{ParseError, Span};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Number(f64),
+    Ident(String),
+    /// A quoted string, or a symbol in the data section that isn't a number or keyword.
+    Str(String),
+    Punct(&'static str),
+    Eof,
+}
+
+/// Punctuation, longest first.
+const PUNCT: &[&str] = &["..", ":=", "<=", ">=", "==", "!=", "<>", "&&", "||", ";", ":", ",", "(", ")", "[",
+                         "]", "{", "}", "+", "-", "*", "/", "<", ">", "=", "!", "."];
+
+/// Keywords of the data section, which are kept as identifiers.
+const DATA_KEYWORDS: &[&str] = &["set", "param", "default", "end"];
+
+fn is_data_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "_.+-".contains(c)
+}
+
+fn is_number_start(s: &str) -> bool {
+    s.starts_with('.') && s[1..].starts_with(|c: char| c.is_ascii_digit())
+}
+
+struct Lexer<'a> {
+    src: &'a str,
+    pos: usize,
+    line: usize,
+    line_start: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn span(&self, start: usize) -> Span {
+        Span { start, end: self.pos, line: self.line, column: start - self.line_start + 1 }
+    }
+
+    fn error(&self, message: String, start: usize) -> ParseError {
+        ParseError::new(message, self.span(start))
+    }
+
+    fn skip_trivia(&mut self) -> Result<(), ParseError> {
+        loop {
+            let rest = &self.src[self.pos..];
+            let skip = if rest.starts_with('#') {
+                rest.find('\n').unwrap_or(rest.len())
+            } else if let Some(comment) = rest.strip_prefix("/*") {
+                match comment.find("*/") {
+                    Some(end) => end + 4,
+                    None => return Err(self.error("unterminated comment".to_string(), self.pos)),
+                }
+            } else {
+                match rest.chars().next() {
+                    Some(c) if c.is_whitespace() => c.len_utf8(),
+                    _ => return Ok(()),
+                }
+            };
+            for (i, c) in rest[..skip].char_indices() {
+                if c == '\n' {
+                    self.line += 1;
+                    self.line_start = self.pos + i + 1;
+                }
+            }
+            self.pos += skip;
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, ParseError> {
+        let start = self.pos;
+        let bytes = self.src.as_bytes();
+        let mut end = self.pos;
+        while end < bytes.len() && bytes[end].is_ascii_digit() {
+            end += 1;
+        }
+        // `1..5` is a range, not the number `1.` followed by `.5`.
+        if end < bytes.len() && bytes[end] == b'.' && bytes.get(end + 1) != Some(&b'.') {
+            end += 1;
+            while end < bytes.len() && bytes[end].is_ascii_digit() {
+                end += 1;
+            }
+        }
+        if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+            let mut exp = end + 1;
+            if exp < bytes.len() && (bytes[exp] == b'+' || bytes[exp] == b'-') {
+                exp += 1;
+            }
+            if exp < bytes.len() && bytes[exp].is_ascii_digit() {
+                end = exp;
+                while end < bytes.len() && bytes[end].is_ascii_digit() {
+                    end += 1;
+                }
+            }
+        }
+        self.pos = end;
+        self.src[start..end].parse().map_err(|_| self.error(format!("invalid number `{}`", &self.src[start..end]), start))
+    }
+
+    fn string(&mut self, quote: char) -> Result<String, ParseError> {
+        let start = self.pos;
+        let rest = &self.src[self.pos + 1..];
+        match rest.find(quote) {
+            Some(end) if !rest[..end].contains('\n') => {
+                self.pos += end + 2;
+                Ok(rest[..end].to_string())
+            }
+            _ => {
+                self.pos += 1;
+                Err(self.error("unterminated string".to_string(), start))
+            }
+        }
+    }
+
+    fn next_token(&mut self, data: bool) -> Result<(Token, Span), ParseError> {
+        self.skip_trivia()?;
+        let start = self.pos;
+        let rest = &self.src[self.pos..];
+        let c = match rest.chars().next() {
+            Some(c) => c,
+            None => return Ok((Token::Eof, self.span(start))),
+        };
+        let token = if c == '\'' || c == '"' {
+            Token::Str(self.string(c)?)
+        } else if data && ((is_data_char(c) && c != '.') || is_number_start(rest)) {
+            let len = rest.find(|c| !is_data_char(c)).unwrap_or(rest.len());
+            let word = &rest[..len];
+            self.pos += len;
+            match word.parse() {
+                Ok(value) if !word.chars().any(|c| c.is_ascii_alphabetic() && c != 'e' && c != 'E') => {
+                    Token::Number(value)
+                }
+                _ if DATA_KEYWORDS.contains(&word) => Token::Ident(word.to_string()),
+                _ => Token::Str(word.to_string()),
+            }
+        } else if c.is_ascii_digit() || is_number_start(rest) {
+            Token::Number(self.number()?)
+        } else if rest.starts_with("s.t.") {
+            self.pos += 4;
+            Token::Ident("s.t.".to_string())
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let len = rest.find(|c: char| !c.is_ascii_alphanumeric() && c != '_').unwrap_or(rest.len());
+            self.pos += len;
+            Token::Ident(rest[..len].to_string())
+        } else {
+            match PUNCT.iter().find(|p| rest.starts_with(*p)) {
+                Some(p) => {
+                    self.pos += p.len();
+                    Token::Punct(p)
+                }
+                None => {
+                    self.pos += c.len_utf8();
+                    return Err(self.error(format!("unexpected character `{}`", c), start));
+                }
+            }
+        };
+        Ok((token, self.span(start)))
+    }
+}
+
+/// Splits `src` into tokens, switching to data mode after `data;`, or from the start if `data`
+/// is `true`.
+pub fn tokenize(src: &str, mut data: bool) -> Result<Vec<(Token, Span)>, ParseError> {
+    let mut lexer = Lexer { src, pos: 0, line: 1, line_start: 0 };
+    let mut tokens: Vec<(Token, Span)> = Vec::new();
+    loop {
+        let (token, span) = lexer.next_token(data)?;
+        let done = token == Token::Eof;
+        if token == Token::Punct(";") && !data {
+            let n = tokens.len();
+            let statement_start = n < 2 || tokens[n - 2].0 == Token::Punct(";");
+            if statement_start && n >= 1 && tokens[n - 1].0 == Token::Ident("data".to_string()) {
+                data = true;
+            }
+        }
+        tokens.push((token, span));
+        if done {
+            return Ok(tokens);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tokenize, Token};
+
+    fn tokens(src: &str) -> Vec<Token> {
+        let mut tokens: Vec<Token> = tokenize(src, false).unwrap().into_iter().map(|(t, _)| t).collect();
+        tokens.pop();
+        tokens
+    }
+
+    #[test]
+    fn modes() {
+        let ident = |s: &str| Token::Ident(s.to_string());
+        assert_eq!(tokens("s.t. c{i in 1..n}: x[i] <= 2.5e1; # comment\ndata; set J := San-Diego 1 -2.5 .;"),
+                   vec![ident("s.t."), ident("c"), Token::Punct("{"), ident("i"), ident("in"), Token::Number(1.0),
+                        Token::Punct(".."), ident("n"), Token::Punct("}"), Token::Punct(":"), ident("x"),
+                        Token::Punct("["), ident("i"), Token::Punct("]"), Token::Punct("<="), Token::Number(25.0),
+                        Token::Punct(";"), ident("data"), Token::Punct(";"), ident("set"),
+                        Token::Str("J".to_string()), Token::Punct(":="), Token::Str("San-Diego".to_string()),
+                        Token::Number(1.0), Token::Number(-2.5), Token::Punct("."), Token::Punct(";")]);
+    }
+}
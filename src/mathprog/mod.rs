@@ -0,0 +1,252 @@
+//! Importing models written in a subset of GNU MathProg (GMPL).
+//!
+//! lpsolve only reads MathProg through an external XLI plugin, which this crate doesn't build.
+//! This module parses the model and data sections in Rust and expands them into a `ModelData`,
+//! naming columns and rows like glpsol does, `x[i,j]` for a member of an indexed variable.
+//!
+//! ```
+//! use lpsolve::mathprog;
+//!
+//! let model = mathprog::parse("set I;\n\
+//!                              param c{I};\n\
+//!                              var x{I} >= 0, <= 4;\n\
+//!                              maximize profit: sum{i in I} c[i] * x[i];\n\
+//!                              s.t. cap: sum{i in I} x[i] <= 6;\n\
+//!                              data;\n\
+//!                              set I := a b;\n\
+//!                              param c := a 3 b 2;\n\
+//!                              end;").unwrap();
+//! let data = model.to_model_data().unwrap();
+//! assert_eq!(data.columns[1].name, Some("x[b]".to_string()));
+//! assert_eq!(data.objective, vec![(1, 3.0), (2, 2.0)]);
+//! ```
+//!
+//! The supported subset is:
+//!
+//! * `set` declarations of simple sets, given in the data section or by `:= 1..n`, `:= {a, b}`
+//!   or the name of another set. Indexed sets and set operations like `union` are not supported.
+//!   A range like `1..n` must have finite ends and fewer than ten million members, and a domain
+//!   like `{i in I, j in J}` at most ten million.
+//! * `param` declarations with an optional domain, `default`, checks like `>= 0`, and a value
+//!   computed with `:=`. The data section gives values as a list, `param c := a 1 b 2;`, or as a
+//!   table, `param d : x y := a 1 2 b 3 4;`, where `.` leaves an entry to the default.
+//! * `var` declarations with bounds (`>= 0`, `<= u[i]`, `= 1`), `integer` and `binary`.
+//!   Like in MathProg, a variable without a lower bound is free.
+//! * One `maximize` or `minimize` objective and any number of constraints, with or without
+//!   `s.t.` or `subject to`. A constraint is `lhs op rhs` or a double inequality
+//!   `lo <= expr <= hi`.
+//! * Expressions built from numbers, parameters, variables, `+ - * /`, and `sum` over an
+//!   indexing expression `{i in I, j in 1..n: i != j}`. The condition may use comparisons,
+//!   `and`, `or` and `not`.
+//!
+//! `solve` is accepted and ignored, as are `display` and `printf` statements.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use {ModelData, Problem};
+use lp_format::{format_number, ParseError, Span};
+
+mod lexer;
+mod parser;
+mod eval;
+
+/// A set element or parameter value: a number or a symbol.
+#[derive(Debug, Clone)]
+pub enum Value {
+    Num(f64),
+    Sym(String),
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::Num(a), Value::Num(b)) => a == b,
+            (Value::Sym(a), Value::Sym(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Value {}
+
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match *self {
+            // Adding 0.0 turns -0.0 into 0.0, which compares equal.
+            Value::Num(v) => (v + 0.0).to_bits().hash(state),
+            Value::Sym(ref s) => s.hash(state),
+        }
+    }
+}
+
+impl PartialOrd for Value {
+    /// Numbers order before symbols, like in MathProg.
+    fn partial_cmp(&self, other: &Value) -> Option<Ordering> {
+        match (self, other) {
+            (Value::Num(a), Value::Num(b)) => a.partial_cmp(b),
+            (Value::Sym(a), Value::Sym(b)) => a.partial_cmp(b),
+            (Value::Num(_), Value::Sym(_)) => Some(Ordering::Less),
+            (Value::Sym(_), Value::Num(_)) => Some(Ordering::Greater),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Value::Num(v) => f.write_str(&format_number(v)),
+            Value::Sym(ref s) => f.write_str(s),
+        }
+    }
+}
+
+/// A parsed MathProg model and its data.
+#[derive(Debug, Clone)]
+pub struct Model {
+    statements: Vec<parser::Statement>,
+    data: parser::Data,
+}
+
+/// Parse a model, including its data section if it has one.
+pub fn parse(src: &str) -> Result<Model, ParseError> {
+    let (statements, data) = parser::Parser::new(lexer::tokenize(src, false)?).model()?;
+    Ok(Model { statements, data })
+}
+
+impl Model {
+    /// Add the statements of a separate data file, like glpsol's `--data` option.
+    ///
+    /// The text may start with `data;`. Data for a set or parameter can only be given once.
+    pub fn add_data(&mut self, src: &str) -> Result<(), ParseError> {
+        let mut tokens = lexer::tokenize(src, true)?;
+        if tokens.len() > 2 && tokens[0].0 == lexer::Token::Str("data".to_string())
+            && tokens[1].0 == lexer::Token::Punct(";") {
+            tokens.drain(..2);
+        }
+        let mut data = parser::Data::default();
+        parser::Parser::new(tokens).data(&mut data)?;
+        for (name, values) in data.sets {
+            if self.data.sets.contains_key(&name) {
+                return Err(ParseError::new(format!("data for set `{}` is given twice", name), values.1));
+            }
+            self.data.sets.insert(name, values);
+        }
+        for (name, param) in data.params {
+            if self.data.params.contains_key(&name) {
+                return Err(ParseError::new(format!("data for parameter `{}` is given twice", name), param.span));
+            }
+            self.data.params.insert(name, param);
+        }
+        Ok(())
+    }
+
+    /// Expand the model over its data.
+    ///
+    /// Columns are created for every member of every variable, in declaration order, and rows for
+    /// every member of every constraint.
+    pub fn to_model_data(&self) -> Result<ModelData, ParseError> {
+        eval::instantiate(&self.statements, &self.data)
+    }
+
+    /// Build a `Problem` from the model.
+    ///
+    /// Fails with an error at the start of the model if lpsolve rejects it.
+    pub fn to_problem(&self) -> Result<Problem, ParseError> {
+        self.to_model_data()?.to_problem()
+            .ok_or_else(|| ParseError::new("lpsolve rejected the model".to_string(), Span::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {ConstraintType, SolveStatus};
+    use super::parse;
+
+    /// The transportation problem from the GLPK examples.
+    const TRANSP: &str = "\
+        set I;  /* canning plants */\n\
+        set J;  # markets\n\
+        param a{i in I};\n\
+        param b{j in J};\n\
+        param d{i in I, j in J};\n\
+        param f;\n\
+        param c{i in I, j in J} := f * d[i,j] / 1000;\n\
+        var x{i in I, j in J} >= 0;\n\
+        minimize cost: sum{i in I, j in J} c[i,j] * x[i,j];\n\
+        s.t. supply{i in I}: sum{j in J} x[i,j] <= a[i];\n\
+        s.t. demand{j in J}: sum{i in I} x[i,j] >= b[j];\n\
+        solve;\n\
+        display x;\n\
+        data;\n\
+        set I := Seattle San-Diego;\n\
+        set J := New-York Chicago Topeka;\n\
+        param a := Seattle 350\n San-Diego 600;\n\
+        param b := New-York 325 Chicago 300 Topeka 275;\n\
+        param d :              New-York   Chicago   Topeka :=\n\
+                  Seattle      2.5        1.7       1.8\n\
+                  San-Diego    2.5        1.8       1.4  ;\n\
+        param f := 90;\n\
+        end;\n";
+
+    #[test]
+    fn transportation() {
+        let model = parse(TRANSP).unwrap();
+        let data = model.to_model_data().unwrap();
+        assert_eq!(data.columns.len(), 6);
+        assert_eq!(data.columns[4].name, Some("x[San-Diego,Chicago]".to_string()));
+        assert_eq!(data.rows[1].name, Some("supply[San-Diego]".to_string()));
+        assert_eq!((data.rows[2].kind, data.rows[2].rhs), (ConstraintType::Ge, 325.0));
+
+        let mut lp = model.to_problem().unwrap();
+        assert_eq!(lp.solve(), SolveStatus::Optimal);
+        assert!((lp.get_objective() - 153.675).abs() < 1e-9);
+    }
+
+    #[test]
+    fn separate_data_and_conditions() {
+        let mut model = parse("param n integer, >= 1;\n\
+                               param w{1..n} default 1;\n\
+                               var y{i in 1..n, j in i+1..n: w[i] + w[j] <= 3} binary;\n\
+                               maximize total: sum{i in 1..n, j in i+1..n: w[i] + w[j] <= 3} (w[i] + w[j]) * y[i,j];\n\
+                               pick: 1 <= sum{i in 1..n, j in i+1..n: not (w[i] + w[j] > 3)} y[i,j] <= 2;\n\
+                               c{i in 1..n}: sum{j in i+1..n: w[i] + w[j] <= 3} y[i,j] <= 1;").unwrap();
+        model.add_data("data; param n := 4; param w := 1 2 3 2 4 .;").unwrap();
+        let data = model.to_model_data().unwrap();
+        let names: Vec<String> = data.columns.iter().map(|c| c.name.clone().unwrap()).collect();
+        assert_eq!(names, ["y[1,2]", "y[1,4]", "y[2,3]", "y[2,4]", "y[3,4]"]);
+        assert!(data.columns.iter().all(|c| c.integer && c.upper == 1.0));
+        assert_eq!((data.rows[0].kind, data.rows[0].rhs, data.rows[0].range), (ConstraintType::Le, 2.0, Some(1.0)));
+        assert_eq!(data.rows.len(), 5);
+        assert!(data.rows[4].coefficients.is_empty());
+        assert!(model.add_data("param n := 3;").is_err());
+    }
+
+    #[test]
+    fn errors() {
+        let e = parse("var x;\nminimize z: x * x;").unwrap().to_model_data().unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 13: the product of two variables is not linear");
+        let e = parse("var x;\ns.t. c: x + y >= 0;").unwrap().to_model_data().unwrap_err();
+        assert_eq!((e.message.as_str(), e.span.line, e.span.column), ("`y` is not declared", 2, 13));
+        let e = parse("param p;\nvar x;\nminimize z: p * x;").unwrap().to_model_data().unwrap_err();
+        assert_eq!(e.message, "no value for `p`");
+        let e = parse("param p >= 0;\ndata;\nparam p := -1;").unwrap().to_model_data().unwrap_err();
+        assert_eq!(e.span.line, 1);
+        assert!(parse("var x\nminimize z: x;").is_err());
+        let e = parse("var x{1..1e300};").unwrap().to_model_data().unwrap_err();
+        assert_eq!(e.to_string(), "line 1, column 7: the range has more than 10000000 members");
+        let e = parse("param p;\nvar x{1..p};\ndata;\nparam p := 1e400;").unwrap().to_model_data().unwrap_err();
+        assert_eq!(e.message, "the range is not finite");
+        let e = parse("var x;\ns.t. c{i in 1..9999999, j in 1..9999999: i = j}: x >= i;").unwrap().to_model_data().unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 7: the domain has more than 10000000 members");
+    }
+
+    #[test]
+    fn subscript_of_a_set_member() {
+        let data = parse("set I;\nvar x{I};\ns.t. c: x[1] >= 0;\ndata; set I := 1 2;").unwrap()
+            .to_model_data().unwrap();
+        assert_eq!(data.rows.len(), 1);
+        assert_eq!(data.rows[0].coefficients, vec![(1, 1.0)]);
+    }
+}
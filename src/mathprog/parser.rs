@@ -0,0 +1,661 @@
+//! Syntax tree and recursive-descent parser for the model and data sections.
+
+use std::collections::HashMap;
+
+use lp_format::{Ident, ParseError, Span};
+use super::lexer::Token;
+use super::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CmpOp {
+    Lt,
+    Le,
+    Eq,
+    Ne,
+    Ge,
+    Gt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprKind {
+    Number(f64),
+    Str(String),
+    /// A dummy index, parameter or variable, with its subscripts.
+    Ref(Ident, Vec<Expr>),
+    Neg(Box<Expr>),
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+    Sum(Box<Domain>, Box<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cond {
+    Compare(Box<Expr>, CmpOp, Box<Expr>),
+    And(Box<Cond>, Box<Cond>),
+    Or(Box<Cond>, Box<Cond>),
+    Not(Box<Cond>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SetExpr {
+    Named(Ident),
+    /// `from..to`, with a step of 1.
+    Range(Expr, Expr),
+    /// `{a, b, c}`.
+    List(Vec<Expr>),
+}
+
+/// An indexing expression like `{i in I, j in 1..n: i != j}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Domain {
+    pub entries: Vec<(Option<Ident>, SetExpr)>,
+    pub condition: Option<Cond>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Set {
+        name: Ident,
+        value: Option<SetExpr>,
+    },
+    Param {
+        name: Ident,
+        domain: Option<Domain>,
+        checks: Vec<(CmpOp, Expr)>,
+        default: Option<Expr>,
+        value: Option<Expr>,
+    },
+    Var {
+        name: Ident,
+        domain: Option<Domain>,
+        bounds: Vec<(CmpOp, Expr)>,
+        integer: bool,
+        binary: bool,
+    },
+    Objective {
+        name: Ident,
+        maximize: bool,
+        expr: Expr,
+    },
+    Constraint {
+        name: Ident,
+        domain: Option<Domain>,
+        lhs: Expr,
+        op: CmpOp,
+        rhs: Expr,
+        range: Option<(CmpOp, Expr)>,
+    },
+}
+
+/// The values of a parameter from the data section.
+///
+/// `entries` lists subscripts followed by the value, or for a table, the row subscript followed by
+/// one value per column. `None` stands for `.`, which leaves the value to the default.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamData {
+    pub default: Option<Value>,
+    pub columns: Option<Vec<Value>>,
+    pub entries: Vec<Option<Value>>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Data {
+    pub sets: HashMap<String, (Vec<Value>, Span)>,
+    pub params: HashMap<String, ParamData>,
+}
+
+const RESERVED: &[&str] = &["and", "or", "not", "in", "sum", "data", "end", "solve"];
+
+pub struct Parser {
+    tokens: Vec<(Token, Span)>,
+    pos: usize,
+}
+
+impl Parser {
+    pub fn new(tokens: Vec<(Token, Span)>) -> Parser {
+        Parser { tokens, pos: 0 }
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos].0
+    }
+
+    fn peek_at(&self, n: usize) -> &Token {
+        &self.tokens[(self.pos + n).min(self.tokens.len() - 1)].0
+    }
+
+    fn span(&self) -> Span {
+        self.tokens[self.pos].1
+    }
+
+    /// The span from `start` to the end of the previous token.
+    fn span_from(&self, start: Span) -> Span {
+        let end = if self.pos > 0 { self.tokens[self.pos - 1].1.end } else { start.end };
+        Span { end: end.max(start.start), ..start }
+    }
+
+    fn advance(&mut self) -> (Token, Span) {
+        let token = self.tokens[self.pos].clone();
+        if self.pos + 1 < self.tokens.len() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn at_punct(&self, p: &str) -> bool {
+        match *self.peek() {
+            Token::Punct(q) => q == p,
+            _ => false,
+        }
+    }
+
+    fn eat_punct(&mut self, p: &str) -> bool {
+        if self.at_punct(p) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn at_keyword(&self, word: &str) -> bool {
+        match *self.peek() {
+            Token::Ident(ref name) => name == word,
+            _ => false,
+        }
+    }
+
+    fn eat_keyword(&mut self, word: &str) -> bool {
+        if self.at_keyword(word) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn unexpected(&self, expected: &str) -> ParseError {
+        let found = match *self.peek() {
+            Token::Number(v) => format!("number `{}`", v),
+            Token::Ident(ref name) | Token::Str(ref name) => format!("`{}`", name),
+            Token::Punct(p) => format!("`{}`", p),
+            Token::Eof => "end of input".to_string(),
+        };
+        ParseError::new(format!("expected {}, found {}", expected, found), self.span())
+    }
+
+    fn expect_punct(&mut self, p: &str) -> Result<(), ParseError> {
+        if self.eat_punct(p) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("`{}`", p)))
+        }
+    }
+
+    fn ident(&mut self) -> Result<Ident, ParseError> {
+        match self.peek().clone() {
+            Token::Ident(ref name) if !RESERVED.contains(&name.as_str()) => {
+                let (_, span) = self.advance();
+                Ok(Ident { name: name.clone(), span })
+            }
+            _ => Err(self.unexpected("a name")),
+        }
+    }
+
+    /// Parses the model section, and the data section if there is one.
+    pub fn model(&mut self) -> Result<(Vec<Statement>, Data), ParseError> {
+        let mut statements = Vec::new();
+        let mut data = Data::default();
+        loop {
+            match self.peek().clone() {
+                Token::Eof => break,
+                Token::Ident(ref word) => match word.as_str() {
+                    "end" => {
+                        self.advance();
+                        self.expect_punct(";")?;
+                        break;
+                    }
+                    "data" => {
+                        self.advance();
+                        self.expect_punct(";")?;
+                        self.data(&mut data)?;
+                        break;
+                    }
+                    "solve" => {
+                        self.advance();
+                        self.expect_punct(";")?;
+                    }
+                    "display" | "printf" => self.skip_statement(),
+                    _ => statements.push(self.statement()?),
+                },
+                _ => return Err(self.unexpected("a statement")),
+            }
+        }
+        match *self.peek() {
+            Token::Eof => Ok((statements, data)),
+            _ => Err(self.unexpected("end of input")),
+        }
+    }
+
+    fn skip_statement(&mut self) {
+        let mut depth = 0;
+        loop {
+            match self.advance().0 {
+                Token::Punct("{") | Token::Punct("(") | Token::Punct("[") => depth += 1,
+                Token::Punct("}") | Token::Punct(")") | Token::Punct("]") => depth -= 1,
+                Token::Punct(";") if depth <= 0 => return,
+                Token::Eof => return,
+                _ => {}
+            }
+        }
+    }
+
+    fn statement(&mut self) -> Result<Statement, ParseError> {
+        if self.eat_keyword("set") {
+            let name = self.ident()?;
+            if self.at_punct("{") {
+                return Err(ParseError::new("indexed sets are not supported".to_string(), self.span()));
+            }
+            let value = if self.eat_punct(":=") { Some(self.set_expr()?) } else { None };
+            self.expect_punct(";")?;
+            return Ok(Statement::Set { name, value });
+        }
+        if self.eat_keyword("param") {
+            let name = self.ident()?;
+            let domain = self.optional_domain()?;
+            let (mut checks, mut default, mut value) = (Vec::new(), None, None);
+            while !self.eat_punct(";") {
+                if self.eat_punct(",") || self.eat_keyword("integer") || self.eat_keyword("binary")
+                    || self.eat_keyword("symbolic") {
+                    continue;
+                }
+                if self.eat_keyword("default") {
+                    default = Some(self.expr()?);
+                } else if self.eat_punct(":=") {
+                    value = Some(self.expr()?);
+                } else if let Some(op) = self.compare_op() {
+                    checks.push((op, self.expr()?));
+                } else {
+                    return Err(self.unexpected("a parameter attribute or `;`"));
+                }
+            }
+            return Ok(Statement::Param { name, domain, checks, default, value });
+        }
+        if self.eat_keyword("var") {
+            let name = self.ident()?;
+            let domain = self.optional_domain()?;
+            let (mut bounds, mut integer, mut binary) = (Vec::new(), false, false);
+            while !self.eat_punct(";") {
+                if self.eat_punct(",") {
+                    continue;
+                }
+                if self.eat_keyword("integer") {
+                    integer = true;
+                } else if self.eat_keyword("binary") {
+                    binary = true;
+                } else {
+                    match self.compare_op() {
+                        Some(op) if op == CmpOp::Le || op == CmpOp::Ge || op == CmpOp::Eq => {
+                            bounds.push((op, self.expr()?))
+                        }
+                        _ => return Err(self.unexpected("a variable attribute or `;`")),
+                    }
+                }
+            }
+            return Ok(Statement::Var { name, domain, bounds, integer, binary });
+        }
+        let objective = match *self.peek() {
+            Token::Ident(ref word) if word == "maximize" || word == "minimize" => Some(word == "maximize"),
+            _ => None,
+        };
+        if let Some(maximize) = objective {
+            self.advance();
+            let name = self.ident()?;
+            self.expect_punct(":")?;
+            let expr = self.expr()?;
+            self.expect_punct(";")?;
+            return Ok(Statement::Objective { name, maximize, expr });
+        }
+
+        if !self.eat_keyword("s.t.") && self.eat_keyword("subject") && !self.eat_keyword("to") {
+            return Err(self.unexpected("`to`"));
+        }
+        let name = self.ident()?;
+        let domain = self.optional_domain()?;
+        self.expect_punct(":")?;
+        let lhs = self.expr()?;
+        let op = self.constraint_op()?;
+        let rhs = self.expr()?;
+        let range = match *self.peek() {
+            Token::Punct(";") => None,
+            _ => {
+                let op = self.constraint_op()?;
+                Some((op, self.expr()?))
+            }
+        };
+        self.expect_punct(";")?;
+        Ok(Statement::Constraint { name, domain, lhs, op, rhs, range })
+    }
+
+    fn compare_op(&mut self) -> Option<CmpOp> {
+        let op = match *self.peek() {
+            Token::Punct("<") => CmpOp::Lt,
+            Token::Punct("<=") => CmpOp::Le,
+            Token::Punct("=") | Token::Punct("==") => CmpOp::Eq,
+            Token::Punct("!=") | Token::Punct("<>") => CmpOp::Ne,
+            Token::Punct(">=") => CmpOp::Ge,
+            Token::Punct(">") => CmpOp::Gt,
+            _ => return None,
+        };
+        self.advance();
+        Some(op)
+    }
+
+    fn constraint_op(&mut self) -> Result<CmpOp, ParseError> {
+        let span = self.span();
+        match self.compare_op() {
+            Some(op) if op == CmpOp::Le || op == CmpOp::Ge || op == CmpOp::Eq => Ok(op),
+            Some(_) => Err(ParseError::new("constraints only allow `<=`, `>=` and `=`".to_string(), span)),
+            None => Err(self.unexpected("`<=`, `>=` or `=`")),
+        }
+    }
+
+    fn optional_domain(&mut self) -> Result<Option<Domain>, ParseError> {
+        if self.at_punct("{") {
+            self.domain().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn domain(&mut self) -> Result<Domain, ParseError> {
+        let start = self.span();
+        self.expect_punct("{")?;
+        let mut entries = Vec::new();
+        loop {
+            let dummy = match (self.peek(), self.peek_at(1)) {
+                (Token::Ident(_), Token::Ident(word)) if word == "in" => {
+                    let dummy = self.ident()?;
+                    self.advance();
+                    Some(dummy)
+                }
+                _ => None,
+            };
+            entries.push((dummy, self.set_expr()?));
+            if !self.eat_punct(",") {
+                break;
+            }
+        }
+        let condition = if self.eat_punct(":") { Some(self.condition()?) } else { None };
+        self.expect_punct("}")?;
+        Ok(Domain { entries, condition, span: self.span_from(start) })
+    }
+
+    fn set_expr(&mut self) -> Result<SetExpr, ParseError> {
+        if self.eat_punct("{") {
+            let mut items = Vec::new();
+            if !self.eat_punct("}") {
+                loop {
+                    items.push(self.expr()?);
+                    if self.eat_punct("}") {
+                        break;
+                    }
+                    self.expect_punct(",")?;
+                }
+            }
+            return Ok(SetExpr::List(items));
+        }
+        let from = self.expr()?;
+        if self.eat_punct("..") {
+            return Ok(SetExpr::Range(from, self.expr()?));
+        }
+        match from.kind {
+            ExprKind::Ref(name, ref subscripts) if subscripts.is_empty() => Ok(SetExpr::Named(name)),
+            _ => Err(ParseError::new("expected a set".to_string(), from.span)),
+        }
+    }
+
+    fn condition(&mut self) -> Result<Cond, ParseError> {
+        let mut cond = self.and_condition()?;
+        while self.eat_keyword("or") || self.eat_punct("||") {
+            cond = Cond::Or(Box::new(cond), Box::new(self.and_condition()?));
+        }
+        Ok(cond)
+    }
+
+    fn and_condition(&mut self) -> Result<Cond, ParseError> {
+        let mut cond = self.not_condition()?;
+        while self.eat_keyword("and") || self.eat_punct("&&") {
+            cond = Cond::And(Box::new(cond), Box::new(self.not_condition()?));
+        }
+        Ok(cond)
+    }
+
+    fn not_condition(&mut self) -> Result<Cond, ParseError> {
+        if self.eat_keyword("not") || self.eat_punct("!") {
+            return Ok(Cond::Not(Box::new(self.not_condition()?)));
+        }
+        if self.at_punct("(") {
+            // Either a parenthesized condition or an expression starting with a parenthesis.
+            let pos = self.pos;
+            self.advance();
+            if let Ok(cond) = self.condition() {
+                if self.eat_punct(")") && !self.at_comparison() {
+                    return Ok(cond);
+                }
+            }
+            self.pos = pos;
+        }
+        let lhs = self.expr()?;
+        match self.compare_op() {
+            Some(op) => Ok(Cond::Compare(Box::new(lhs), op, Box::new(self.expr()?))),
+            None => Err(self.unexpected("a comparison")),
+        }
+    }
+
+    fn at_comparison(&self) -> bool {
+        match *self.peek() {
+            Token::Punct(p) => ["<", "<=", "=", "==", "!=", "<>", ">=", ">", "+", "-", "*", "/"].contains(&p),
+            _ => false,
+        }
+    }
+
+    pub fn expr(&mut self) -> Result<Expr, ParseError> {
+        let start = self.span();
+        let mut lhs = self.term()?;
+        loop {
+            let op = match *self.peek() {
+                Token::Punct("+") => BinOp::Add,
+                Token::Punct("-") => BinOp::Sub,
+                _ => return Ok(lhs),
+            };
+            self.advance();
+            let rhs = self.term()?;
+            lhs = Expr { kind: ExprKind::Binary(op, Box::new(lhs), Box::new(rhs)), span: self.span_from(start) };
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, ParseError> {
+        let start = self.span();
+        let mut lhs = self.unary()?;
+        loop {
+            let op = match *self.peek() {
+                Token::Punct("*") => BinOp::Mul,
+                Token::Punct("/") => BinOp::Div,
+                _ => return Ok(lhs),
+            };
+            self.advance();
+            let rhs = self.unary()?;
+            lhs = Expr { kind: ExprKind::Binary(op, Box::new(lhs), Box::new(rhs)), span: self.span_from(start) };
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        let start = self.span();
+        if self.eat_punct("-") {
+            let operand = self.unary()?;
+            return Ok(Expr { kind: ExprKind::Neg(Box::new(operand)), span: self.span_from(start) });
+        }
+        if self.eat_punct("+") {
+            return self.unary();
+        }
+        if self.eat_keyword("sum") {
+            let domain = self.domain()?;
+            // The operand of `sum` extends over products, so `sum{i in I} c[i] * x[i]` sums the products.
+            let body = self.term()?;
+            return Ok(Expr { kind: ExprKind::Sum(Box::new(domain), Box::new(body)), span: self.span_from(start) });
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr, ParseError> {
+        let start = self.span();
+        let kind = match self.peek().clone() {
+            Token::Number(value) => {
+                self.advance();
+                ExprKind::Number(value)
+            }
+            Token::Str(s) => {
+                self.advance();
+                ExprKind::Str(s)
+            }
+            Token::Punct("(") => {
+                self.advance();
+                let expr = self.expr()?;
+                self.expect_punct(")")?;
+                return Ok(Expr { kind: expr.kind, span: self.span_from(start) });
+            }
+            Token::Ident(_) => {
+                let name = self.ident()?;
+                let mut subscripts = Vec::new();
+                if self.eat_punct("[") {
+                    loop {
+                        subscripts.push(self.expr()?);
+                        if self.eat_punct("]") {
+                            break;
+                        }
+                        self.expect_punct(",")?;
+                    }
+                }
+                ExprKind::Ref(name, subscripts)
+            }
+            _ => return Err(self.unexpected("an expression")),
+        };
+        Ok(Expr { kind, span: self.span_from(start) })
+    }
+
+    /// A value in the data section, or `None` for `.`.
+    fn data_value(&mut self) -> Result<Option<Value>, ParseError> {
+        match self.peek().clone() {
+            Token::Number(value) => {
+                self.advance();
+                Ok(Some(Value::Num(value)))
+            }
+            Token::Str(s) | Token::Ident(s) => {
+                self.advance();
+                Ok(Some(Value::Sym(s)))
+            }
+            Token::Punct(".") => {
+                self.advance();
+                Ok(None)
+            }
+            _ => Err(self.unexpected("a value")),
+        }
+    }
+
+    fn data_name(&mut self) -> Result<Ident, ParseError> {
+        match self.peek().clone() {
+            Token::Str(name) | Token::Ident(name) => {
+                let (_, span) = self.advance();
+                Ok(Ident { name, span })
+            }
+            _ => Err(self.unexpected("a name")),
+        }
+    }
+
+    /// Parses data statements until `end;` or the end of input.
+    pub fn data(&mut self, data: &mut Data) -> Result<(), ParseError> {
+        loop {
+            if self.eat_keyword("end") {
+                self.expect_punct(";")?;
+                break;
+            }
+            if *self.peek() == Token::Eof {
+                break;
+            }
+            let set = if self.eat_keyword("set") {
+                true
+            } else if self.eat_keyword("param") {
+                false
+            } else {
+                return Err(self.unexpected("`set`, `param` or `end`"));
+            };
+            let name = self.data_name()?;
+            if set {
+                self.expect_punct(":=")?;
+                let mut values = Vec::new();
+                while !self.eat_punct(";") {
+                    if self.eat_punct(",") {
+                        continue;
+                    }
+                    let span = self.span();
+                    match self.data_value()? {
+                        Some(value) => values.push(value),
+                        None => return Err(ParseError::new("expected a set element".to_string(), span)),
+                    }
+                }
+                if data.sets.insert(name.name.clone(), (values, name.span)).is_some() {
+                    return Err(ParseError::new(format!("data for set `{}` is given twice", name.name), name.span));
+                }
+                continue;
+            }
+
+            let default = if self.eat_keyword("default") { self.data_value()? } else { None };
+            let columns = if self.eat_punct(":") {
+                let mut columns = Vec::new();
+                while !self.at_punct(":=") {
+                    let span = self.span();
+                    match self.data_value()? {
+                        Some(value) => columns.push(value),
+                        None => return Err(ParseError::new("expected a column label".to_string(), span)),
+                    }
+                }
+                Some(columns)
+            } else {
+                None
+            };
+            let mut entries = Vec::new();
+            if self.eat_punct(":=") {
+                while !self.at_punct(";") {
+                    if !self.eat_punct(",") {
+                        entries.push(self.data_value()?);
+                    }
+                }
+            }
+            self.expect_punct(";")?;
+            let param = ParamData { default, columns, entries, span: name.span };
+            if data.params.insert(name.name.clone(), param).is_some() {
+                return Err(ParseError::new(format!("data for parameter `{}` is given twice", name.name), name.span));
+            }
+        }
+        match *self.peek() {
+            Token::Eof => Ok(()),
+            _ => Err(self.unexpected("end of input")),
+        }
+    }
+}
@@ -0,0 +1,395 @@
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use libc::c_int;
+
+use ConstraintType;
+
+/// A column of a `Model`.
+///
+/// Variables are plain handles: they don't borrow the model, and combine with `f64` and each other
+/// into `LinearExpr`s through the arithmetic operators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Variable {
+    col: c_int,
+}
+
+impl Variable {
+    /// The variable for column `col`, counting from 1. This does not check that the column exists.
+    pub fn from_column(col: c_int) -> Variable {
+        Variable { col }
+    }
+
+    /// The column of this variable in the underlying `Problem`.
+    pub fn column(self) -> c_int {
+        self.col
+    }
+}
+
+/// A row of a `Model`, as returned by `Model::add_constraint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Constraint {
+    row: c_int,
+}
+
+impl Constraint {
+    /// The constraint for row `row`, counting from 1. This does not check that the row exists.
+    pub fn from_row(row: c_int) -> Constraint {
+        Constraint { row }
+    }
+
+    /// The row of this constraint in the underlying `Problem`.
+    pub fn row(self) -> c_int {
+        self.row
+    }
+}
+
+/// A linear expression: a sum of variables times coefficients, plus a constant.
+///
+/// The terms are kept in the order they were added and may name a variable more than once. They
+/// are merged when the expression becomes part of a constraint or objective, or by `simplify`.
+#[derive(Debug, Clone, Default)]
+pub struct LinearExpr {
+    terms: Vec<(Variable, f64)>,
+    constant: f64,
+}
+
+impl LinearExpr {
+    /// The empty expression, which is zero.
+    pub fn new() -> LinearExpr {
+        LinearExpr::default()
+    }
+
+    /// The terms of the expression as `(variable, coefficient)` pairs.
+    pub fn terms(&self) -> &[(Variable, f64)] {
+        &self.terms
+    }
+
+    /// The constant part of the expression.
+    pub fn constant(&self) -> f64 {
+        self.constant
+    }
+
+    /// Adds `coeff * var` to the expression.
+    pub fn add_term(&mut self, var: Variable, coeff: f64) {
+        self.terms.push((var, coeff));
+    }
+
+    /// Merges the terms of each variable into one, sorts them by column and drops the ones that
+    /// cancel out.
+    pub fn simplify(&mut self) {
+        self.terms.sort_by_key(|&(var, _)| var);
+        let mut merged: Vec<(Variable, f64)> = Vec::with_capacity(self.terms.len());
+        for &(var, coeff) in &self.terms {
+            match merged.last_mut() {
+                Some(last) if last.0 == var => last.1 += coeff,
+                _ => merged.push((var, coeff)),
+            }
+        }
+        merged.retain(|&(_, coeff)| coeff != 0.0);
+        self.terms = merged;
+    }
+
+    /// Evaluates the expression, taking the value of each variable from `value`.
+    pub fn evaluate<F: FnMut(Variable) -> f64>(&self, mut value: F) -> f64 {
+        self.terms.iter().fold(self.constant, |acc, &(var, coeff)| acc + coeff * value(var))
+    }
+
+    /// The constraint `self <= rhs`.
+    pub fn le<E: Into<LinearExpr>>(self, rhs: E) -> LinearConstraint {
+        LinearConstraint::new(self, ConstraintType::Le, rhs.into())
+    }
+
+    /// The constraint `self >= rhs`.
+    pub fn ge<E: Into<LinearExpr>>(self, rhs: E) -> LinearConstraint {
+        LinearConstraint::new(self, ConstraintType::Ge, rhs.into())
+    }
+
+    /// The constraint `self == rhs`.
+    pub fn eq<E: Into<LinearExpr>>(self, rhs: E) -> LinearConstraint {
+        LinearConstraint::new(self, ConstraintType::Eq, rhs.into())
+    }
+}
+
+impl From<Variable> for LinearExpr {
+    fn from(var: Variable) -> LinearExpr {
+        LinearExpr { terms: vec![(var, 1.0)], constant: 0.0 }
+    }
+}
+
+impl<'a> From<&'a Variable> for LinearExpr {
+    fn from(var: &'a Variable) -> LinearExpr {
+        LinearExpr::from(*var)
+    }
+}
+
+/// Adds up variables or expressions, as in `sum(x.values())` or `sum(items.map(|i| c[i] * x[i]))`.
+///
+/// The sum of nothing is zero.
+pub fn sum<I>(iter: I) -> LinearExpr where I: IntoIterator, I::Item: Into<LinearExpr> {
+    iter.into_iter().fold(LinearExpr::new(), |acc, e| acc + e)
+}
+
+impl Sum<LinearExpr> for LinearExpr {
+    fn sum<I: Iterator<Item=LinearExpr>>(iter: I) -> LinearExpr {
+        sum(iter)
+    }
+}
+
+impl Sum<Variable> for LinearExpr {
+    fn sum<I: Iterator<Item=Variable>>(iter: I) -> LinearExpr {
+        sum(iter)
+    }
+}
+
+impl<'a> Sum<&'a Variable> for LinearExpr {
+    fn sum<I: Iterator<Item=&'a Variable>>(iter: I) -> LinearExpr {
+        sum(iter)
+    }
+}
+
+/// A linear constraint in the form lpsolve stores it: merged terms on the left, a relation, and a
+/// constant on the right.
+///
+/// Built with `LinearExpr::le`, `ge` and `eq`, and added to a model with `Model::add_constraint`.
+#[derive(Debug, Clone)]
+pub struct LinearConstraint {
+    terms: Vec<(Variable, f64)>,
+    kind: ConstraintType,
+    rhs: f64,
+}
+
+impl LinearConstraint {
+    fn new(lhs: LinearExpr, kind: ConstraintType, rhs: LinearExpr) -> LinearConstraint {
+        let mut expr = lhs - rhs;
+        expr.simplify();
+        LinearConstraint { terms: expr.terms, kind, rhs: -expr.constant }
+    }
+
+    /// The terms of the left hand side, one per variable, sorted by column.
+    pub fn terms(&self) -> &[(Variable, f64)] {
+        &self.terms
+    }
+
+    /// The relation between the two sides.
+    pub fn kind(&self) -> ConstraintType {
+        self.kind
+    }
+
+    /// The right hand side.
+    pub fn rhs(&self) -> f64 {
+        self.rhs
+    }
+}
+
+impl<E: Into<LinearExpr>> AddAssign<E> for LinearExpr {
+    fn add_assign(&mut self, rhs: E) {
+        let rhs = rhs.into();
+        self.terms.extend(rhs.terms);
+        self.constant += rhs.constant;
+    }
+}
+
+impl<E: Into<LinearExpr>> SubAssign<E> for LinearExpr {
+    fn sub_assign(&mut self, rhs: E) {
+        *self += -rhs.into();
+    }
+}
+
+impl MulAssign<f64> for LinearExpr {
+    fn mul_assign(&mut self, rhs: f64) {
+        for term in &mut self.terms {
+            term.1 *= rhs;
+        }
+        self.constant *= rhs;
+    }
+}
+
+impl Neg for LinearExpr {
+    type Output = LinearExpr;
+
+    fn neg(mut self) -> LinearExpr {
+        self *= -1.0;
+        self
+    }
+}
+
+impl Neg for Variable {
+    type Output = LinearExpr;
+
+    fn neg(self) -> LinearExpr {
+        -LinearExpr::from(self)
+    }
+}
+
+impl<E: Into<LinearExpr>> Add<E> for LinearExpr {
+    type Output = LinearExpr;
+
+    fn add(mut self, rhs: E) -> LinearExpr {
+        self += rhs;
+        self
+    }
+}
+
+impl<E: Into<LinearExpr>> Sub<E> for LinearExpr {
+    type Output = LinearExpr;
+
+    fn sub(mut self, rhs: E) -> LinearExpr {
+        self -= rhs;
+        self
+    }
+}
+
+impl Mul<f64> for LinearExpr {
+    type Output = LinearExpr;
+
+    fn mul(mut self, rhs: f64) -> LinearExpr {
+        self *= rhs;
+        self
+    }
+}
+
+impl<E: Into<LinearExpr>> Add<E> for Variable {
+    type Output = LinearExpr;
+
+    fn add(self, rhs: E) -> LinearExpr {
+        LinearExpr::from(self) + rhs
+    }
+}
+
+impl<E: Into<LinearExpr>> Sub<E> for Variable {
+    type Output = LinearExpr;
+
+    fn sub(self, rhs: E) -> LinearExpr {
+        LinearExpr::from(self) - rhs
+    }
+}
+
+/// Implements the mixed arithmetic between a scalar type and `Variable` or `LinearExpr`, in terms
+/// of the `f64` operations above. `i32` is covered so that integer literals work, as in `2 * x`.
+macro_rules! scalar_ops {
+    ($($t:ty)*) => {$(
+        impl From<$t> for LinearExpr {
+            fn from(constant: $t) -> LinearExpr {
+                LinearExpr { terms: Vec::new(), constant: f64::from(constant) }
+            }
+        }
+
+        impl Mul<$t> for Variable {
+            type Output = LinearExpr;
+
+            fn mul(self, rhs: $t) -> LinearExpr {
+                LinearExpr::from(self) * f64::from(rhs)
+            }
+        }
+
+        impl Div<$t> for Variable {
+            type Output = LinearExpr;
+
+            fn div(self, rhs: $t) -> LinearExpr {
+                LinearExpr::from(self) * (1.0 / f64::from(rhs))
+            }
+        }
+
+        impl Div<$t> for LinearExpr {
+            type Output = LinearExpr;
+
+            fn div(self, rhs: $t) -> LinearExpr {
+                self * (1.0 / f64::from(rhs))
+            }
+        }
+
+        impl Add<Variable> for $t {
+            type Output = LinearExpr;
+
+            fn add(self, rhs: Variable) -> LinearExpr {
+                rhs + self
+            }
+        }
+
+        impl Add<LinearExpr> for $t {
+            type Output = LinearExpr;
+
+            fn add(self, rhs: LinearExpr) -> LinearExpr {
+                rhs + self
+            }
+        }
+
+        impl Sub<Variable> for $t {
+            type Output = LinearExpr;
+
+            fn sub(self, rhs: Variable) -> LinearExpr {
+                -rhs + self
+            }
+        }
+
+        impl Sub<LinearExpr> for $t {
+            type Output = LinearExpr;
+
+            fn sub(self, rhs: LinearExpr) -> LinearExpr {
+                -rhs + self
+            }
+        }
+
+        impl Mul<Variable> for $t {
+            type Output = LinearExpr;
+
+            fn mul(self, rhs: Variable) -> LinearExpr {
+                rhs * self
+            }
+        }
+
+        impl Mul<LinearExpr> for $t {
+            type Output = LinearExpr;
+
+            fn mul(self, rhs: LinearExpr) -> LinearExpr {
+                rhs * f64::from(self)
+            }
+        }
+    )*};
+}
+
+scalar_ops!(f64 i32);
+
+impl Mul<i32> for LinearExpr {
+    type Output = LinearExpr;
+
+    fn mul(self, rhs: i32) -> LinearExpr {
+        self * f64::from(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ConstraintType;
+    use super::*;
+
+    #[test]
+    fn operators() {
+        let (x, y) = (Variable::from_column(1), Variable::from_column(2));
+        let e = 2.0 * x + 3.0 * y - x - (1.0 - y) * 2.0 + -(y / 4.0);
+        assert_eq!(e.terms(), &[(x, 2.0), (y, 3.0), (x, -1.0), (y, 2.0), (y, -0.25)]);
+        assert_eq!(e.constant(), -2.0);
+        assert_eq!(e.evaluate(|v| if v == x { 1.0 } else { 4.0 }), 18.0);
+
+        let e = 2 * x - y * 3 + (x + 1) / 2 - 4;
+        assert_eq!(e.terms(), &[(x, 2.0), (y, -3.0), (x, 0.5)]);
+        assert_eq!(e.constant(), -3.5);
+
+        let e: LinearExpr = [x, y, x].iter().sum();
+        assert_eq!(e.terms(), &[(x, 1.0), (y, 1.0), (x, 1.0)]);
+        assert_eq!(sum(Vec::<Variable>::new()).terms(), &[]);
+    }
+
+    #[test]
+    fn constraints_are_normalized() {
+        let (x, y) = (Variable::from_column(1), Variable::from_column(2));
+        let c = (y + 2.0 * x + 1.0).le(x + y + 5.0);
+        assert_eq!(c.terms(), &[(x, 1.0)]);
+        assert_eq!((c.kind(), c.rhs()), (ConstraintType::Le, 4.0));
+
+        let c = (x - y).ge(-y * 2.0);
+        assert_eq!(c.terms(), &[(x, 1.0), (y, 1.0)]);
+        assert_eq!((c.kind(), c.rhs()), (ConstraintType::Ge, 0.0));
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+
+use super::{Constraint, LinearExpr, Model, Solution, VarKind, Variable};
+
+/// A soft target for an expression, added with `Model::add_goal`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Goal {
+    /// The row `expr + under - over == target`.
+    pub row: Constraint,
+    /// How far the expression falls short of the target.
+    pub under: Variable,
+    /// How far the expression exceeds the target.
+    pub over: Variable,
+    pub target: f64,
+    pub under_penalty: f64,
+    pub over_penalty: f64,
+}
+
+/// How close a solution came to a goal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoalOutcome {
+    pub goal: Goal,
+    /// The name of the goal's row.
+    pub name: String,
+    /// The value of the expression.
+    pub value: f64,
+    /// How far the expression fell short of the target.
+    pub under: f64,
+    /// How far the expression exceeded the target.
+    pub over: f64,
+}
+
+/// Deviations up to this fraction of the target count as meeting it.
+const TOLERANCE: f64 = 1e-9;
+
+impl GoalOutcome {
+    /// How far the expression missed the target, in either direction.
+    pub fn miss(&self) -> f64 {
+        self.under + self.over
+    }
+
+    /// Whether the expression hit the target, up to rounding.
+    pub fn met(&self) -> bool {
+        self.miss() <= TOLERANCE * (1.0 + self.goal.target.abs())
+    }
+}
+
+impl fmt::Display for GoalOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} against a target of {}", self.name, self.value, self.goal.target)?;
+        if self.met() {
+            write!(f, ", met")
+        } else if self.under >= self.over {
+            write!(f, ", {} under", self.under)
+        } else {
+            write!(f, ", {} over", self.over)
+        }
+    }
+}
+
+impl Model {
+    /// Adds a soft target for `expr`, and returns it.
+    ///
+    /// Two deviation variables measure how far `expr` falls short of `target` and how far it
+    /// exceeds it, tied to `expr` by the row `expr + under - over == target`. The deviations are
+    /// penalized in the objective, per unit, by `under_penalty` and `over_penalty`: added when it
+    /// is minimized and subtracted when it is maximized. The penalties stay in the objective when
+    /// it is replaced with `minimize` or `maximize`.
+    ///
+    /// Panics if a penalty is negative, which would reward missing the target.
+    pub fn add_goal<E: Into<LinearExpr>>(&mut self, expr: E, target: f64, under_penalty: f64, over_penalty: f64)
+                                         -> Goal {
+        assert!(under_penalty >= 0.0 && over_penalty >= 0.0, "the penalties of a goal can't be negative");
+        let sign = self.penalty_sign();
+        let infinity = self.problem().get_infinite();
+        let under = self.add_column("", VarKind::Continuous, 0.0, infinity, sign * under_penalty);
+        let over = self.add_column("", VarKind::Continuous, 0.0, infinity, sign * over_penalty);
+        let row = self.add_constraint((expr.into() + under - over).eq(target));
+        let goal = Goal { row, under, over, target, under_penalty, over_penalty };
+        self.goals.push(goal);
+        goal
+    }
+
+    /// The goals of the model, in the order they were added.
+    pub fn goals(&self) -> &[Goal] {
+        &self.goals
+    }
+
+    /// How close `solution` came to each goal, in the order they were added.
+    ///
+    /// The deviations are measured from the value of the expression, not read from the deviation
+    /// variables: without a penalty to keep them apart, both can be positive at once.
+    pub fn goal_report(&self, solution: &Solution) -> Vec<GoalOutcome> {
+        self.goals.iter().map(|&goal| {
+            let value = goal.target - solution[goal.under] + solution[goal.over];
+            let (under, over) = ((goal.target - value).max(0.0), (value - goal.target).max(0.0));
+            GoalOutcome { goal, name: self.constraint_name(goal.row), value, under, over }
+        }).collect()
+    }
+
+    /// The sign of the penalties in the objective, which is maximized or minimized.
+    pub(super) fn penalty_sign(&self) -> f64 {
+        if self.problem().is_maxim() { -1.0 } else { 1.0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use modeling::{Model, VarKind};
+
+    #[test]
+    fn working_hours() {
+        let mut model = Model::new();
+        let ann = model.add_var("ann", VarKind::Continuous, 0.0..);
+        let bob = model.add_var("bob", VarKind::Continuous, 0.0..);
+        // There are 70 hours of work to share.
+        let work = model.add_constraint((ann + bob).eq(70.0));
+        // Bob's overtime and idle time are dearer than Ann's.
+        model.add_goal(ann, 40.0, 1.0, 2.0);
+        model.add_goal(bob, 40.0, 2.0, 3.0);
+        assert_eq!(model.goals().len(), 2);
+
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        let solution = model.solve();
+        assert!(close(solution.objective(), 10.0));
+        let report = model.goal_report(&solution);
+        assert!(!report[0].met() && report[1].met());
+        assert!(close(report[0].value, 30.0) && close(report[0].under, 10.0) && close(report[0].miss(), 10.0));
+        let r = &report[0];
+        assert_eq!(r.to_string(), format!("{}: {} against a target of 40, {} under", r.name, r.value, r.under));
+
+        // Overtime falls to Ann, even though every hour of hers is worth a little more.
+        assert!(model.problem_mut().set_rh(work.row(), 85.0));
+        model.maximize(0.1 * ann);
+        let solution = model.solve();
+        assert!(close(solution.objective(), 4.5 - 10.0));
+        let report = model.goal_report(&solution);
+        assert!(close(report[0].value, 45.0) && close(report[0].over, 5.0));
+        assert!(report[1].met());
+    }
+
+    #[test]
+    fn without_penalties() {
+        let mut model = Model::new();
+        let x = model.add_var("x", VarKind::Continuous, 5.0..=5.0);
+        let goal = model.add_goal(x, 5.0, 0.0, 0.0);
+        // Nothing keeps both deviations from being positive.
+        assert!(model.problem_mut().set_lower_bound(goal.over.column(), 2.0));
+        let solution = model.solve();
+        assert_eq!((solution[goal.under], solution[goal.over]), (2.0, 2.0));
+        let report = model.goal_report(&solution);
+        assert_eq!((report[0].value, report[0].under, report[0].over), (5.0, 0.0, 0.0));
+        assert!(report[0].met());
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::Write;
+
+/// A key of an indexed family of variables, as added by `Model::add_vars`.
+///
+/// Each key contributes `_`-separated parts to the column name, so the key `(3, 7)` of a family
+/// named `x` becomes the column `x_3_7`. Implemented for integers, `char`, strings and tuples of up
+/// to four keys.
+pub trait IndexKey {
+    /// Appends `_` and the parts of this key to `name`.
+    fn append_to(&self, name: &mut String);
+}
+
+macro_rules! display_keys {
+    ($($t:ty)*) => {$(
+        impl IndexKey for $t {
+            fn append_to(&self, name: &mut String) {
+                let _ = write!(name, "_{}", self);
+            }
+        }
+    )*};
+}
+
+display_keys!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize char String);
+
+impl IndexKey for &str {
+    fn append_to(&self, name: &mut String) {
+        name.push('_');
+        name.push_str(self);
+    }
+}
+
+macro_rules! tuple_keys {
+    ($(($($t:ident $i:tt),+))*) => {$(
+        impl<$($t: IndexKey),+> IndexKey for ($($t,)+) {
+            fn append_to(&self, name: &mut String) {
+                $(self.$i.append_to(name);)+
+            }
+        }
+    )*};
+}
+
+tuple_keys! {
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndexKey;
+
+    fn name<K: IndexKey>(key: K) -> String {
+        let mut name = String::from("x");
+        key.append_to(&mut name);
+        name
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(name(3), "x_3");
+        assert_eq!(name((3, 7)), "x_3_7");
+        assert_eq!(name(("a", 'b', -1i64, String::from("c"))), "x_a_b_-1_c");
+    }
+}
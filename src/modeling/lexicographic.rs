@@ -0,0 +1,161 @@
+use libc::c_int;
+
+use SolveStatus;
+use super::{Constraint, LinearExpr, Model, Solution};
+
+/// The direction of an objective.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sense {
+    Minimize,
+    Maximize,
+}
+
+/// How far an objective may move away from its optimum once it is fixed in favour of the next.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// At most this much.
+    Absolute(f64),
+    /// At most this fraction of the optimum's magnitude.
+    Relative(f64),
+}
+
+impl Tolerance {
+    fn slack(self, optimum: f64) -> f64 {
+        match self {
+            Tolerance::Absolute(tol) => tol,
+            Tolerance::Relative(tol) => tol * optimum.abs(),
+        }
+    }
+}
+
+impl Model {
+    /// Sets `expr` as the objective function, in the direction of `sense`.
+    pub fn set_objective_sense<E: Into<LinearExpr>>(&mut self, expr: E, sense: Sense) {
+        match sense {
+            Sense::Minimize => self.minimize(expr),
+            Sense::Maximize => self.maximize(expr),
+        }
+    }
+
+    /// Optimizes several objectives in order of priority, and returns the solution of each step.
+    ///
+    /// After each objective is optimized, it is kept within its tolerance of its optimal value by a
+    /// new row while the next one is optimized, starting from the final basis of the previous solve.
+    /// The steps stop early if a solve doesn't find a solution, in which case the last solution
+    /// returned tells why. The model is left with the last objective that was set, and without
+    /// the rows that were added.
+    pub fn solve_lexicographic(&mut self, objectives: &[(LinearExpr, Sense, Tolerance)]) -> Vec<Solution> {
+        let first_row = self.problem().num_rows() + 1;
+        let mut solutions = Vec::with_capacity(objectives.len());
+        for (i, &(ref expr, sense, tolerance)) in objectives.iter().enumerate() {
+            self.set_objective_sense(expr.clone(), sense);
+            let solution = self.solve();
+            let status = solution.status();
+            // The objective also holds the penalties of any goals, which the new row doesn't.
+            let optimum = solution.evaluate(expr);
+            solutions.push(solution);
+            if status != SolveStatus::Optimal && status != SolveStatus::Suboptimal {
+                break;
+            }
+            if i + 1 < objectives.len() {
+                let slack = tolerance.slack(optimum);
+                let basis = self.basis();
+                let row = match sense {
+                    Sense::Minimize => self.add_constraint(expr.clone().le(optimum + slack)),
+                    Sense::Maximize => self.add_constraint(expr.clone().ge(optimum - slack)),
+                };
+                if let Some(basis) = basis {
+                    self.warm_start(basis, row);
+                }
+            }
+        }
+        for row in (first_row..=self.problem().num_rows()).rev() {
+            assert!(self.problem_mut().del_constraint(row));
+        }
+        solutions
+    }
+
+    /// The basic variables of the final basis, if there is one.
+    fn basis(&self) -> Option<Vec<c_int>> {
+        let mut basis = vec![0; self.problem().num_rows() as usize + 1];
+        if self.problem().get_basis(&mut basis, false) { Some(basis) } else { None }
+    }
+
+    /// Starts the next solve from `basis`, which was taken before `row` was added as the last row.
+    /// The slack of the new row joins the basis.
+    fn warm_start(&mut self, mut basis: Vec<c_int>, row: Constraint) {
+        // Columns are numbered after the rows, so they move up by one.
+        for index in &mut basis[1..] {
+            if index.abs() >= row.row() {
+                *index += index.signum();
+            }
+        }
+        basis.push(-row.row());
+        // An invalid basis is rejected and lpsolve starts from its default one instead.
+        self.problem_mut().set_basis(&basis, false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use SolveStatus;
+    use modeling::{Model, VarKind};
+    use super::{Sense, Tolerance};
+
+    #[test]
+    fn priorities() {
+        let mut model = Model::new();
+        let x = model.add_var("x", VarKind::Continuous, 0.0..=10.0);
+        let y = model.add_var("y", VarKind::Continuous, 0.0..=10.0);
+        let z = model.add_var("z", VarKind::Continuous, 0.0..=10.0);
+        model.add_constraint((x + y + z).ge(10.0));
+
+        let solutions = model.solve_lexicographic(&[
+            (x + y + z, Sense::Minimize, Tolerance::Relative(0.1)),
+            (x + 0.0, Sense::Maximize, Tolerance::Absolute(2.0)),
+            (y - z, Sense::Maximize, Tolerance::Absolute(0.0)),
+        ]);
+        assert_eq!(solutions.len(), 3);
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        assert!(close(solutions[0].objective(), 10.0));
+        assert!(close(solutions[1].objective(), 10.0));
+        let last = &solutions[2];
+        assert_eq!(last.status(), SolveStatus::Optimal);
+        assert!(close(last[x], 8.0) && close(last[y], 3.0) && close(last[z], 0.0));
+        assert_eq!(model.problem().num_rows(), 1);
+    }
+
+    #[test]
+    fn stops_when_infeasible() {
+        let mut model = Model::new();
+        let x = model.add_var("x", VarKind::Continuous, 0.0..=1.0);
+        model.add_constraint((1.0 * x).ge(2.0));
+        let solutions = model.solve_lexicographic(&[
+            (x + 0.0, Sense::Minimize, Tolerance::Absolute(0.0)),
+            (x + 0.0, Sense::Maximize, Tolerance::Absolute(0.0)),
+        ]);
+        assert_eq!(solutions.len(), 1);
+        assert_eq!(solutions[0].status(), SolveStatus::Infeasible);
+    }
+
+    #[test]
+    fn with_goals() {
+        let mut model = Model::new();
+        let x = model.add_var("x", VarKind::Continuous, 0.0..=10.0);
+        let y = model.add_var("y", VarKind::Continuous, 0.0..=10.0);
+        model.add_constraint((x + y).le(12.0));
+        // Out of reach, so the penalty of 2.5 stays in the first objective.
+        model.add_goal(x, 15.0, 0.5, 0.0);
+
+        let solutions = model.solve_lexicographic(&[
+            (x + y, Sense::Maximize, Tolerance::Absolute(0.0)),
+            (y - x, Sense::Maximize, Tolerance::Absolute(0.0)),
+        ]);
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        assert!(close(solutions[0].objective(), 9.5));
+        // The sum stays at 12, not at the objective value of 9.5.
+        let last = &solutions[1];
+        assert!(close(last[x], 2.0) && close(last[y], 10.0));
+        assert_eq!(model.problem().num_rows(), 2);
+    }
+}
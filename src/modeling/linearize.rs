@@ -0,0 +1,164 @@
+use SOSType;
+use super::{sum, BigMError, LinearExpr, Model, VarKind, Variable};
+
+impl Model {
+    /// The smallest and largest values of `expr` within the variable bounds.
+    fn range(&self, expr: &mut LinearExpr) -> Result<(f64, f64), BigMError> {
+        expr.simplify();
+        Ok((self.extreme(expr.terms(), false)? + expr.constant(), self.extreme(expr.terms(), true)? + expr.constant()))
+    }
+
+    /// Adds a variable that equals `|expr|`, and returns it.
+    ///
+    /// `expr` is split into a positive and a negative part that share an SOS1 set, so at most one
+    /// of them is nonzero and the result is exact whichever way the objective pushes it. lpsolve
+    /// needs the parts to be bounded, which they are by the bounds of the variables in `expr`, so
+    /// an error is returned if any of those is infinite, and nothing is added to the model.
+    pub fn add_abs<E: Into<LinearExpr>>(&mut self, expr: E) -> Result<Variable, BigMError> {
+        let mut expr = expr.into();
+        let (lower, upper) = self.range(&mut expr)?;
+        let positive = self.add_var("", VarKind::Continuous, 0.0..=upper.max(0.0));
+        let negative = self.add_var("", VarKind::Continuous, 0.0..=(-lower).max(0.0));
+        self.add_constraint((positive - negative).eq(expr));
+        self.add_sos(&format!("abs_{}", positive.column()), SOSType::Type1, &[positive, negative]);
+        let abs = self.add_var("", VarKind::Continuous, 0.0..);
+        self.add_constraint((positive + negative).eq(abs));
+        Ok(abs)
+    }
+
+    /// Adds a variable that equals the largest of `exprs`, and returns it.
+    ///
+    /// The variable is bounded below by every expression, and above by the one a set of binary
+    /// variables chooses, with big-M constraints for the others. The big-M values are derived from
+    /// the bounds of the variables in `exprs`, so an error is returned if any of those is
+    /// infinite, and nothing is added to the model.
+    ///
+    /// Panics if `exprs` is empty.
+    pub fn add_max<E: Into<LinearExpr>>(&mut self, exprs: Vec<E>) -> Result<Variable, BigMError> {
+        self.add_extremum(exprs, true)
+    }
+
+    /// Adds a variable that equals the smallest of `exprs`, and returns it. See `add_max`.
+    ///
+    /// Panics if `exprs` is empty.
+    pub fn add_min<E: Into<LinearExpr>>(&mut self, exprs: Vec<E>) -> Result<Variable, BigMError> {
+        self.add_extremum(exprs, false)
+    }
+
+    fn add_extremum<E: Into<LinearExpr>>(&mut self, exprs: Vec<E>, max: bool) -> Result<Variable, BigMError> {
+        assert!(!exprs.is_empty(), "the maximum or minimum of no expressions is undefined");
+        let mut exprs: Vec<LinearExpr> = exprs.into_iter().map(Into::into).collect();
+        let mut ranges = Vec::with_capacity(exprs.len());
+        for expr in &mut exprs {
+            ranges.push(self.range(expr)?);
+        }
+        // The result lies within the range of the extremum, which bounds how far it can be from
+        // each of the expressions.
+        let pick = if max { f64::max } else { f64::min };
+        let (lower, upper) = ranges[1..].iter().fold(ranges[0], |acc, r| (pick(acc.0, r.0), pick(acc.1, r.1)));
+        let result = self.add_var("", VarKind::Continuous, lower..=upper);
+        let mut choices = Vec::with_capacity(exprs.len());
+        for (expr, (expr_lower, expr_upper)) in exprs.into_iter().zip(ranges) {
+            let chosen = self.add_var("", VarKind::Binary, ..);
+            if max {
+                // expr <= result <= expr + M (1 - chosen)
+                let m = upper - expr_lower;
+                self.add_constraint((result - expr.clone()).ge(0.0));
+                self.add_constraint((result - expr + m * chosen).le(m));
+            } else {
+                // expr - M (1 - chosen) <= result <= expr
+                let m = expr_upper - lower;
+                self.add_constraint((result - expr.clone()).le(0.0));
+                self.add_constraint((result - expr - m * chosen).ge(-m));
+            }
+            choices.push(chosen);
+        }
+        self.add_constraint(sum(&choices).eq(1.0));
+        Ok(result)
+    }
+
+    /// Adds a variable that equals `binary * expr`, and returns it.
+    ///
+    /// `binary` should be a binary variable. The product is written with big-M constraints
+    /// derived from the bounds of the variables in `expr`, so an error is returned if any of them
+    /// is infinite, and nothing is added to the model.
+    pub fn add_product<E: Into<LinearExpr>>(&mut self, binary: Variable, expr: E) -> Result<Variable, BigMError> {
+        let mut expr = expr.into();
+        let (lower, upper) = self.range(&mut expr)?;
+        let product = self.add_var("", VarKind::Continuous, lower.min(0.0)..=upper.max(0.0));
+        // With binary = 0 the product is 0, with binary = 1 it is expr.
+        self.add_constraint((product - upper * binary).le(0.0));
+        self.add_constraint((product - lower * binary).ge(0.0));
+        self.add_constraint((product - expr.clone() - lower * binary).le(-lower));
+        self.add_constraint((product - expr - upper * binary).ge(-upper));
+        Ok(product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use SolveStatus;
+    use modeling::{Model, VarKind, Variable};
+
+    fn fixed(model: &mut Model, value: f64) -> Variable {
+        model.add_var("", VarKind::Continuous, value..=value)
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    /// Checks that `t` takes `expected` whether it is minimized or maximized.
+    fn check(model: &mut Model, t: Variable, expected: f64) {
+        model.maximize(t);
+        let solution = model.solve();
+        assert_eq!(solution.status(), SolveStatus::Optimal);
+        assert!(close(solution[t], expected), "{} is maximized to {}", expected, solution[t]);
+        model.minimize(t);
+        let solution = model.solve();
+        assert_eq!(solution.status(), SolveStatus::Optimal);
+        assert!(close(solution[t], expected), "{} is minimized to {}", expected, solution[t]);
+    }
+
+    #[test]
+    fn abs() {
+        for &(x, y) in &[(1.0, 4.0), (4.0, 1.0), (-2.5, -2.5)] {
+            let mut model = Model::new();
+            let (vx, vy) = (fixed(&mut model, x), fixed(&mut model, y));
+            let t = model.add_abs(vx - vy).unwrap();
+            check(&mut model, t, (x - y).abs());
+        }
+    }
+
+    #[test]
+    fn min_and_max() {
+        for values in &[[2.0, 5.0, -1.0], [-3.0, -3.0, -7.0], [0.0, 1.5, 9.0]] {
+            let mut model = Model::new();
+            let vars: Vec<_> = values.iter().map(|&v| fixed(&mut model, v)).collect();
+            let max = model.add_max(vars.clone()).unwrap();
+            let min = model.add_min(vec![vars[0] + 1.0, vars[1] + 1.0, vars[2] + 1.0]).unwrap();
+            check(&mut model, max, values.iter().cloned().fold(f64::NEG_INFINITY, f64::max));
+            check(&mut model, min, values.iter().cloned().fold(f64::INFINITY, f64::min) + 1.0);
+        }
+    }
+
+    #[test]
+    fn product() {
+        for &(b, x) in &[(0.0, 4.0), (1.0, 4.0), (1.0, -2.0), (0.0, -2.0), (1.0, 7.0)] {
+            let mut model = Model::new();
+            let vb = model.add_var("b", VarKind::Binary, b..=b);
+            let vx = model.add_var("x", VarKind::Continuous, -2.0..=7.0);
+            model.add_constraint((1.0 * vx).eq(x));
+            let t = model.add_product(vb, 2.0 * vx + 1.0).unwrap();
+            check(&mut model, t, b * (2.0 * x + 1.0));
+        }
+
+        let mut model = Model::new();
+        let b = model.add_var("b", VarKind::Binary, ..);
+        let x = model.add_var("x", VarKind::Continuous, 0.0..);
+        assert!(model.add_product(b, x).is_err());
+        assert!(model.add_abs(x).is_err());
+        assert!(model.add_max(vec![x, b]).is_err());
+        assert_eq!(model.problem().num_cols(), 2);
+    }
+}
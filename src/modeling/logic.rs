@@ -0,0 +1,229 @@
+use std::error::Error;
+use std::fmt;
+
+use ConstraintType;
+use super::{sum, Constraint, LinearConstraint, LinearExpr, Model, VarKind, Variable};
+
+/// The error returned when a logical constraint can't be written with big-M values, because a
+/// variable in it has an infinite bound in the direction that matters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigMError {
+    /// The variable with the infinite bound.
+    pub variable: Variable,
+    /// The name of the variable.
+    pub name: String,
+    /// Whether the upper bound is the infinite one, rather than the lower.
+    pub upper: bool,
+}
+
+impl fmt::Display for BigMError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no big-M value can be derived because {} has an infinite {} bound",
+               self.name, if self.upper { "upper" } else { "lower" })
+    }
+}
+
+impl Error for BigMError {}
+
+impl Model {
+    /// The largest value `terms` can take within the variable bounds if `max`, otherwise the
+    /// smallest.
+    pub(super) fn extreme(&self, terms: &[(Variable, f64)], max: bool) -> Result<f64, BigMError> {
+        let infinity = self.problem().get_infinite();
+        let mut total = 0.0;
+        for &(var, coeff) in terms {
+            let upper = (coeff > 0.0) == max;
+            let col = var.column();
+            let bound = if upper { self.problem().get_upper_bound(col) } else { self.problem().get_lower_bound(col) };
+            match bound {
+                Some(bound) if bound.abs() < infinity => total += coeff * bound,
+                _ => return Err(BigMError { variable: var, name: self.var_name(var), upper }),
+            }
+        }
+        Ok(total)
+    }
+
+    /// The big-M values of the `<=` and `>=` sides of `constraint`, as far as it has them.
+    fn big_m(&self, constraint: &LinearConstraint) -> Result<(Option<f64>, Option<f64>), BigMError> {
+        let rhs = constraint.rhs();
+        let le = if constraint.kind() != ConstraintType::Ge {
+            Some((self.extreme(constraint.terms(), true)? - rhs).max(0.0))
+        } else {
+            None
+        };
+        let ge = if constraint.kind() != ConstraintType::Le {
+            Some((rhs - self.extreme(constraint.terms(), false)?).max(0.0))
+        } else {
+            None
+        };
+        Ok((le, ge))
+    }
+
+    /// Adds rows that enforce `constraint` when the binary `indicator` is 1 and leave it free when
+    /// it is 0, and returns them: one row for `<=` and `>=`, two for `==`.
+    ///
+    /// The big-M values are the smallest that make the rows redundant when `indicator` is 0, as
+    /// derived from the bounds of the variables in `constraint`. An error is returned if a bound
+    /// they depend on is infinite, and nothing is added to the model.
+    ///
+    /// Panics if `indicator` isn't an integer variable with bounds within 0 and 1.
+    pub fn add_indicator(&mut self, indicator: Variable, constraint: LinearConstraint)
+                         -> Result<Vec<Constraint>, BigMError> {
+        let col = indicator.column();
+        let binary = self.problem().is_integer(col) == Some(true)
+            && self.problem().get_lower_bound(col).map_or(false, |lower| lower >= 0.0)
+            && self.problem().get_upper_bound(col).map_or(false, |upper| upper <= 1.0);
+        assert!(binary, "the indicator {} is not a binary variable", self.var_name(indicator));
+        let (le, ge) = self.big_m(&constraint)?;
+        let rhs = constraint.rhs();
+        let mut rows = Vec::new();
+        // lhs <= rhs + M (1 - indicator)
+        if let Some(m) = le {
+            rows.push(self.add_constraint((constraint.lhs() + m * indicator).le(rhs + m)));
+        }
+        // lhs >= rhs - M (1 - indicator)
+        if let Some(m) = ge {
+            rows.push(self.add_constraint((constraint.lhs() - m * indicator).ge(rhs - m)));
+        }
+        Ok(rows)
+    }
+
+    /// Requires that at least `k` of `constraints` hold, and returns the binary variables that
+    /// indicate which do.
+    ///
+    /// Each constraint gets an indicator as in `add_indicator`. If any of them can't be
+    /// reformulated, nothing is added to the model.
+    pub fn add_at_least(&mut self, constraints: Vec<LinearConstraint>, k: usize) -> Result<Vec<Variable>, BigMError> {
+        for constraint in &constraints {
+            self.big_m(constraint)?;
+        }
+        let indicators: Vec<Variable> = constraints.into_iter().map(|constraint| {
+            let indicator = self.add_var("", VarKind::Binary, ..);
+            self.add_indicator(indicator, constraint).expect("bounds were checked above");
+            indicator
+        }).collect();
+        self.add_constraint(sum(&indicators).ge(k as f64));
+        Ok(indicators)
+    }
+
+    /// Requires that `first` or `second` holds, or both. Returns their indicators as in
+    /// `add_at_least`.
+    pub fn add_either_or(&mut self, first: LinearConstraint, second: LinearConstraint)
+                         -> Result<Vec<Variable>, BigMError> {
+        self.add_at_least(vec![first, second], 1)
+    }
+
+    /// Requires that `conclusion` holds whenever `premise` does.
+    ///
+    /// This is written as "`premise` is violated, or `conclusion` holds". A linear program can't
+    /// express a strict inequality, so the premise counts as violated once it is off by at least
+    /// `epsilon`, for example 1 if its variables and coefficients are integers. Solutions that
+    /// violate the premise by less may fail to satisfy the conclusion. Returns the indicators of
+    /// the violated premise, one side for `<=` and `>=` and both for `==`, followed by that of the
+    /// conclusion.
+    pub fn add_implication(&mut self, premise: LinearConstraint, conclusion: LinearConstraint, epsilon: f64)
+                           -> Result<Vec<Variable>, BigMError> {
+        let mut alternatives = Vec::new();
+        if premise.kind() != ConstraintType::Ge {
+            alternatives.push(premise.lhs().ge(premise.rhs() + epsilon));
+        }
+        if premise.kind() != ConstraintType::Le {
+            alternatives.push(premise.lhs().le(premise.rhs() - epsilon));
+        }
+        alternatives.push(conclusion);
+        self.add_at_least(alternatives, 1)
+    }
+}
+
+impl LinearConstraint {
+    /// The left hand side as an expression.
+    pub fn lhs(&self) -> LinearExpr {
+        let mut expr = LinearExpr::new();
+        for &(var, coeff) in self.terms() {
+            expr.add_term(var, coeff);
+        }
+        expr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use SolveStatus;
+    use modeling::{Model, VarKind};
+
+    #[test]
+    fn indicator() {
+        let mut model = Model::new();
+        let x = model.add_var("x", VarKind::Continuous, -5.0..=20.0);
+        let b = model.add_var("b", VarKind::Binary, ..);
+        let rows = model.add_indicator(b, (2.0 * x).le(10.0)).unwrap();
+        assert_eq!(rows.len(), 1);
+        // M is 40 - 10.
+        let mut row = [0.0; 3];
+        assert!(model.problem().get_row(&mut row, rows[0].row()));
+        assert_eq!(row, [0.0, 2.0, 30.0]);
+        assert_eq!(model.problem().get_rh(rows[0].row()), Some(40.0));
+
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        model.maximize(x + 100.0 * b);
+        let solution = model.solve();
+        assert!(solution[b] == 1.0 && close(solution[x], 5.0));
+
+        model.maximize(x - 100.0 * b);
+        let solution = model.solve();
+        assert!(solution[b] == 0.0 && close(solution[x], 20.0));
+
+        let rows = model.add_indicator(b, (x + 0.0).eq(3.0)).unwrap();
+        assert_eq!(rows.len(), 2);
+        model.maximize(100.0 * b - x);
+        assert!(close(model.solve()[x], 3.0));
+    }
+
+    #[test]
+    fn infinite_bounds() {
+        let mut model = Model::new();
+        let x = model.add_var("x", VarKind::Continuous, 0.0..);
+        let b = model.add_var("b", VarKind::Binary, ..);
+        assert!(model.add_indicator(b, (-1.0 * x).le(-2.0)).is_ok());
+        let err = model.add_indicator(b, (1.0 * x).le(2.0)).unwrap_err();
+        assert_eq!((err.variable, err.upper), (x, true));
+        assert_eq!(err.to_string(), "no big-M value can be derived because x has an infinite upper bound");
+        assert_eq!(model.problem().num_rows(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "the indicator b is not a binary variable")]
+    fn integer_indicator() {
+        let mut model = Model::new();
+        let x = model.add_var("x", VarKind::Continuous, 0.0..=10.0);
+        let b = model.add_var("b", VarKind::Integer, 0.0..=2.0);
+        let _ = model.add_indicator(b, (1.0 * x).le(2.0));
+    }
+
+    #[test]
+    fn at_least_and_implication() {
+        let mut model = Model::new();
+        let x = model.add_var("x", VarKind::Integer, 0.0..=10.0);
+        let y = model.add_var("y", VarKind::Integer, 0.0..=10.0);
+        // The jobs x and y take 3 and 4 units of time on one machine and can't overlap.
+        let start_x = model.add_var("start_x", VarKind::Integer, 0.0..=10.0);
+        let start_y = model.add_var("start_y", VarKind::Integer, 0.0..=10.0);
+        model.add_either_or((start_x + 3.0).le(start_y), (start_y + 4.0).le(start_x)).unwrap();
+        model.add_constraint((1.0 * start_y).le(2.0));
+        model.minimize(start_x);
+        let solution = model.solve();
+        assert_eq!(solution.status(), SolveStatus::Optimal);
+        assert!(solution[start_x] >= solution[start_y] + 4.0);
+
+        // If x is at least 5, y must be at most 2.
+        model.add_implication((x + 0.0).ge(5.0), (y + 0.0).le(2.0), 1.0).unwrap();
+        model.maximize(x + y);
+        let solution = model.solve();
+        assert_eq!(solution.objective(), 14.0);
+        assert_eq!((solution[x], solution[y]), (4.0, 10.0));
+
+        let indicators = model.add_at_least(vec![(x + 0.0).ge(9.0), (y + 0.0).ge(9.0), (x + y).le(3.0)], 2).unwrap();
+        assert_eq!(indicators.len(), 3);
+        assert_eq!(model.solve().status(), SolveStatus::Infeasible);
+    }
+}
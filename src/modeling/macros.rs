@@ -0,0 +1,224 @@
+/// Adds a constraint written as a comparison to a `Model`, and returns its `Constraint`.
+///
+/// The comparison is `<=`, `>=` or `==` between two linear expressions in the syntax of the
+/// `LinearExpr` operators, where integer literals may be used for coefficients. A label in front
+/// names the row.
+///
+/// ```
+/// #[macro_use] extern crate lpsolve;
+/// use lpsolve::modeling::{Model, VarKind};
+///
+/// # fn main() {
+/// let mut model = Model::new();
+/// let x = model.add_var("x", VarKind::Continuous, 0.0..);
+/// let y = model.add_var("y", VarKind::Continuous, 0.0..);
+/// constraint!(model, 2*x + 3*y <= 12);
+/// let c = constraint!(model, balance: x - 0.5*y == 1);
+/// assert_eq!(model.constraint_name(c), "balance");
+/// # }
+/// ```
+///
+/// Anything that isn't a single comparison is rejected at compile time:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate lpsolve;
+/// # use lpsolve::modeling::{Model, VarKind};
+/// # fn main() {
+/// # let mut model = Model::new();
+/// # let x = model.add_var("x", VarKind::Continuous, 0.0..);
+/// constraint!(model, 2*x + 3 < 12);
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate lpsolve;
+/// # use lpsolve::modeling::{Model, VarKind};
+/// # fn main() {
+/// # let mut model = Model::new();
+/// # let x = model.add_var("x", VarKind::Continuous, 0.0..);
+/// constraint!(model, 0 <= x <= 12);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! constraint {
+    ($model:expr, $name:ident : $($body:tt)+) => {
+        $model.add_named_constraint(stringify!($name), $crate::__lpsolve_relation!([] $($body)+))
+    };
+    ($model:expr, $($body:tt)+) => {
+        $model.add_constraint($crate::__lpsolve_relation!([] $($body)+))
+    };
+}
+
+/// Sets the objective function of a `Model`, written as `maximize <expr>` or `minimize <expr>`.
+///
+/// ```
+/// #[macro_use] extern crate lpsolve;
+/// use lpsolve::modeling::{Model, VarKind};
+///
+/// # fn main() {
+/// let mut model = Model::new();
+/// let x = model.add_var("x", VarKind::Continuous, 0.0..=4.0);
+/// let y = model.add_var("y", VarKind::Continuous, 0.0..=1.0);
+/// objective!(model, maximize 3*x + 2*y);
+/// assert_eq!(model.problem_mut().solve(), lpsolve::SolveStatus::Optimal);
+/// assert_eq!(model.problem().get_objective(), 14.0);
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate lpsolve;
+/// # use lpsolve::modeling::{Model, VarKind};
+/// # fn main() {
+/// # let mut model = Model::new();
+/// # let x = model.add_var("x", VarKind::Continuous, 0.0..);
+/// objective!(model, max 3*x);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! objective {
+    ($model:expr, maximize $($expr:tt)+) => {
+        $model.maximize($crate::modeling::LinearExpr::from($($expr)+))
+    };
+    ($model:expr, minimize $($expr:tt)+) => {
+        $model.minimize($crate::modeling::LinearExpr::from($($expr)+))
+    };
+    ($model:expr, $($body:tt)*) => {
+        compile_error!("expected `maximize <expression>` or `minimize <expression>`")
+    };
+}
+
+/// Writes several objective and constraint statements against a `Model` in one go.
+///
+/// Each statement ends with a semicolon and is either `maximize <expr>`, `minimize <expr>` or a
+/// constraint as accepted by `constraint!`, which reads much like an lp-format file:
+///
+/// ```
+/// #[macro_use] extern crate lpsolve;
+/// use lpsolve::modeling::{Model, VarKind};
+///
+/// # fn main() {
+/// let mut model = Model::new();
+/// let x = model.add_var("x", VarKind::Continuous, 0.0..);
+/// let y = model.add_var("y", VarKind::Integer, 0.0..);
+/// lp!(model;
+///     maximize 143*x + 60*y;
+///     land: 120*x + 210*y <= 15000;
+///     110*x + 30*y <= 4000;
+///     x + y <= 75;
+/// );
+/// assert_eq!(model.problem().num_rows(), 3);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! lp {
+    ($model:expr; $($body:tt)*) => {
+        $crate::__lpsolve_statements!($model; [] $($body)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lpsolve_statements {
+    ($model:expr; []) => {};
+    ($model:expr; [$($stmt:tt)+]) => {
+        compile_error!("expected `;` after the last statement")
+    };
+    ($model:expr; [maximize $($expr:tt)+] ; $($rest:tt)*) => {
+        $crate::objective!($model, maximize $($expr)+);
+        $crate::__lpsolve_statements!($model; [] $($rest)*)
+    };
+    ($model:expr; [minimize $($expr:tt)+] ; $($rest:tt)*) => {
+        $crate::objective!($model, minimize $($expr)+);
+        $crate::__lpsolve_statements!($model; [] $($rest)*)
+    };
+    ($model:expr; [$($stmt:tt)+] ; $($rest:tt)*) => {
+        $crate::constraint!($model, $($stmt)+);
+        $crate::__lpsolve_statements!($model; [] $($rest)*)
+    };
+    ($model:expr; [$($stmt:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__lpsolve_statements!($model; [$($stmt)* $next] $($rest)*)
+    };
+}
+
+/// Splits a comparison at its operator into a `LinearConstraint`. The left hand side is collected
+/// in brackets, one token at a time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lpsolve_relation {
+    ([$($lhs:tt)+] <= $($rhs:tt)+) => {
+        $crate::__lpsolve_relation!(@rhs le [$($lhs)+] [] $($rhs)+)
+    };
+    ([$($lhs:tt)+] >= $($rhs:tt)+) => {
+        $crate::__lpsolve_relation!(@rhs ge [$($lhs)+] [] $($rhs)+)
+    };
+    ([$($lhs:tt)+] == $($rhs:tt)+) => {
+        $crate::__lpsolve_relation!(@rhs eq [$($lhs)+] [] $($rhs)+)
+    };
+    ([$($lhs:tt)*] < $($rest:tt)*) => {
+        compile_error!("strict inequalities can't be expressed in a linear program, use `<=`")
+    };
+    ([$($lhs:tt)*] > $($rest:tt)*) => {
+        compile_error!("strict inequalities can't be expressed in a linear program, use `>=`")
+    };
+    ([$($lhs:tt)*] = $($rest:tt)*) => {
+        compile_error!("use `==` for an equality constraint")
+    };
+    ([$($lhs:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__lpsolve_relation!([$($lhs)* $next] $($rest)*)
+    };
+    ([$($lhs:tt)*]) => {
+        compile_error!("expected a comparison with `<=`, `>=` or `==` between two expressions")
+    };
+
+    // The right hand side is scanned for a second comparison before it is used.
+    (@rhs $op:ident [$($lhs:tt)+] [$($rhs:tt)+]) => {
+        $crate::modeling::LinearExpr::from($($lhs)+).$op($($rhs)+)
+    };
+    (@rhs $op:ident [$($lhs:tt)+] [$($rhs:tt)*] <= $($rest:tt)*) => {
+        compile_error!("chained comparisons are not supported, add each one as its own constraint")
+    };
+    (@rhs $op:ident [$($lhs:tt)+] [$($rhs:tt)*] >= $($rest:tt)*) => {
+        compile_error!("chained comparisons are not supported, add each one as its own constraint")
+    };
+    (@rhs $op:ident [$($lhs:tt)+] [$($rhs:tt)*] == $($rest:tt)*) => {
+        compile_error!("chained comparisons are not supported, add each one as its own constraint")
+    };
+    (@rhs $op:ident [$($lhs:tt)+] [$($rhs:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__lpsolve_relation!(@rhs $op [$($lhs)+] [$($rhs)* $next] $($rest)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use SolveStatus;
+    use modeling::{Model, VarKind};
+
+    #[test]
+    fn macros() {
+        let mut model = Model::new();
+        let x = model.add_var("x", VarKind::Continuous, 0.0..);
+        let y = model.add_var("y", VarKind::Continuous, 0.0..);
+        let limit = 12.0;
+        let c = constraint!(model, 2*x + 3*y <= limit);
+        let d = constraint!(model, at_least: x + y >= (1 + 1));
+        constraint!(model, 2 * (x - y) == -x + y - 3);
+        objective!(model, maximize 3*x + 2*y);
+
+        assert_eq!(model.constraint_name(d), "at_least");
+        let mut row = [0.0; 3];
+        assert!(model.problem().get_row(&mut row, c.row()));
+        assert_eq!(row, [0.0, 2.0, 3.0]);
+        assert_eq!(model.problem().get_rh(3), Some(-3.0));
+        assert!(model.problem().get_row(&mut row, 3));
+        assert_eq!(row, [0.0, 3.0, -3.0]);
+        assert_eq!(model.problem_mut().solve(), SolveStatus::Optimal);
+        assert!((model.problem().get_objective() - 11.0).abs() < 1e-9);
+
+        lp!(model;
+            minimize x;
+            y <= 3;
+        );
+        assert_eq!(model.problem().num_rows(), 4);
+        assert!(!model.problem().is_maxim());
+    }
+}
@@ -0,0 +1,63 @@
+//! An algebraic modeling layer on top of `Problem`.
+//!
+//! Instead of filling dense rows of coefficients, variables are added to a `Model` as `Variable`
+//! handles, combined with the arithmetic operators into `LinearExpr`s, and turned into constraints
+//! with `le`, `ge` and `eq`. Terms naming the same variable are merged and constants are moved to
+//! the right hand side before the row is handed to lpsolve as a sparse constraint.
+//!
+//! ```
+//! use lpsolve::SolveStatus;
+//! use lpsolve::modeling::{Model, VarKind};
+//!
+//! let mut model = Model::new();
+//! let x = model.add_var("x", VarKind::Continuous, 0.0..);
+//! let y = model.add_var("y", VarKind::Continuous, 0.0..);
+//! model.add_constraint((120.0 * x + 210.0 * y).le(15000.0));
+//! model.add_constraint((110.0 * x + 30.0 * y).le(4000.0));
+//! model.add_constraint((x + y).le(75.0));
+//! model.maximize(143.0 * x + 60.0 * y);
+//!
+//! let solution = model.solve();
+//! assert_eq!(solution.status(), SolveStatus::Optimal);
+//! assert!((solution.objective() - 6315.625).abs() < 1e-6);
+//! assert!((solution[x] - 21.875).abs() < 1e-6);
+//! ```
+//!
+//! The `constraint!`, `objective!` and `lp!` macros accept the same models written closer to
+//! mathematical notation, as in `constraint!(model, 2*x + 3*y <= 12)`.
+//!
+//! Indexed families of variables come from `Model::add_vars`, which returns a map from each key
+//! to its `Variable`, and `sum` adds up whatever ranges over them.
+//! Piecewise-linear functions of a variable are added with `Model::add_piecewise_linear`, and
+//! logical conditions between constraints with `add_indicator`, `add_either_or`, `add_at_least`
+//! and `add_implication`, which derive big-M values from the variable bounds. `add_abs`,
+//! `add_min`, `add_max` and `add_product` add a variable that stands for a nonlinear term.
+//!
+//! `Model::solve_lexicographic` optimizes several objectives in order of priority, and the
+//! `pareto` module finds the trade-offs between them. Soft targets are added with
+//! `Model::add_goal`, which penalizes the deviations from them in the objective, and
+//! `Model::goal_report` tells how far a solution missed each one.
+//!
+//! `Model::solve` returns a `Solution`, a copy of the results that can be queried by `Variable`,
+//! `Constraint` or name and outlives any later changes to the model.
+
+#[macro_use]
+mod macros;
+mod expr;
+mod goal;
+mod index;
+mod lexicographic;
+mod linearize;
+mod logic;
+mod model;
+pub mod pareto;
+mod piecewise;
+mod solution;
+
+pub use self::expr::{Variable, Constraint, LinearExpr, LinearConstraint, sum};
+pub use self::goal::{Goal, GoalOutcome};
+pub use self::index::IndexKey;
+pub use self::lexicographic::{Sense, Tolerance};
+pub use self::logic::BigMError;
+pub use self::model::{Model, VarKind};
+pub use self::solution::Solution;
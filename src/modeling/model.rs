@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::hash::Hash;
+use std::ops::{Bound, RangeBounds};
+
+use libc::c_int;
+
+use {ConstraintType, Problem, SOSType};
+use super::{Constraint, Goal, IndexKey, LinearConstraint, LinearExpr, Solution, Variable};
+
+/// The domain of a variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VarKind {
+    Continuous,
+    Integer,
+    /// An integer restricted to 0 and 1, intersected with any bounds given.
+    Binary,
+}
+
+/// A `Problem` built from `Variable`s and `LinearExpr`s instead of column indices.
+///
+/// The methods here panic if lpsolve fails to grow the model, which it only does when it runs out
+/// of memory, or if they are passed variables or constraints the model doesn't have. Everything
+/// `Problem` offers is still available through `problem` and `problem_mut`.
+#[derive(Clone)]
+pub struct Model {
+    problem: Problem,
+    pub(super) goals: Vec<Goal>,
+}
+
+impl Model {
+    /// Creates an empty model, which is minimized.
+    pub fn new() -> Model {
+        Model::from_problem(Problem::new(0, 0).expect("lpsolve failed to create a model"))
+    }
+
+    /// Wraps an existing problem. Its columns are available through `variable`.
+    pub fn from_problem(problem: Problem) -> Model {
+        Model { problem, goals: Vec::new() }
+    }
+
+    /// The underlying problem.
+    pub fn problem(&self) -> &Problem {
+        &self.problem
+    }
+
+    /// The underlying problem, for everything the model doesn't wrap.
+    pub fn problem_mut(&mut self) -> &mut Problem {
+        &mut self.problem
+    }
+
+    /// Unwraps the underlying problem.
+    pub fn into_problem(self) -> Problem {
+        self.problem
+    }
+
+    /// Adds a variable with the given domain and bounds, such as `0.0..`, `..=10.0` or `..`.
+    ///
+    /// Bounds are always inclusive, because a linear program can't express a strict inequality,
+    /// so `0.0..10.0` is the same as `0.0..=10.0`. Unbounded ends are infinite. An empty `name`
+    /// leaves the column with lpsolve's default name.
+    ///
+    /// Panics if the lower bound is above the upper bound.
+    pub fn add_var<R: RangeBounds<f64>>(&mut self, name: &str, kind: VarKind, bounds: R) -> Variable {
+        let (lower, upper) = self.bounds(name, kind, &bounds);
+        self.add_column(name, kind, lower, upper, 0.0)
+    }
+
+    /// Adds a family of variables, one for each key in `indices`, with the same domain and bounds.
+    ///
+    /// The columns are added in the order of `indices` and named after `name` and their key, so
+    /// the key `(3, 7)` of the family `x` becomes `x_3_7`. An empty `name` leaves them unnamed.
+    ///
+    /// Panics if a key repeats or the lower bound is above the upper bound.
+    pub fn add_vars<K, I, R>(&mut self, name: &str, indices: I, kind: VarKind, bounds: R) -> HashMap<K, Variable>
+        where K: IndexKey + Eq + Hash, I: IntoIterator<Item=K>, R: RangeBounds<f64>
+    {
+        let (lower, upper) = self.bounds(name, kind, &bounds);
+        let indices = indices.into_iter();
+        let mut vars = HashMap::with_capacity(indices.size_hint().0);
+        let mut full_name = String::new();
+        for key in indices {
+            full_name.clear();
+            if !name.is_empty() {
+                full_name.push_str(name);
+                key.append_to(&mut full_name);
+            }
+            let var = self.add_column(&full_name, kind, lower, upper, 0.0);
+            assert!(vars.insert(key, var).is_none(), "duplicate index {:?} in the variables {:?}", full_name, name);
+        }
+        vars
+    }
+
+    /// Resolves the bounds of a new variable against lpsolve's infinity and its kind.
+    fn bounds<R: RangeBounds<f64>>(&self, name: &str, kind: VarKind, bounds: &R) -> (f64, f64) {
+        let infinity = self.problem.get_infinite();
+        let mut lower = match bounds.start_bound() {
+            Bound::Included(&v) | Bound::Excluded(&v) => v,
+            Bound::Unbounded => -infinity,
+        };
+        let mut upper = match bounds.end_bound() {
+            Bound::Included(&v) | Bound::Excluded(&v) => v,
+            Bound::Unbounded => infinity,
+        };
+        if kind == VarKind::Binary {
+            lower = lower.max(0.0);
+            upper = upper.min(1.0);
+        }
+        assert!(lower <= upper, "variable {:?} has lower bound {} above upper bound {}", name, lower, upper);
+        (lower, upper)
+    }
+
+    /// Adds a column with the objective coefficient `cost`.
+    pub(super) fn add_column(&mut self, name: &str, kind: VarKind, lower: f64, upper: f64, cost: f64) -> Variable {
+        let added = if cost == 0.0 {
+            self.problem.add_column_scatter(&[], &[])
+        } else {
+            self.problem.add_column_scatter(&[cost], &[0])
+        };
+        assert!(added, "lpsolve failed to add a column");
+        let var = Variable::from_column(self.problem.num_cols());
+        assert!(self.problem.set_bounds(var.column(), lower, upper));
+        assert!(self.problem.set_integer(var.column(), kind != VarKind::Continuous));
+        if !name.is_empty() {
+            self.set_var_name(var, name);
+        }
+        var
+    }
+
+    /// The variable for an existing column, `None` if `col` is out of bounds.
+    pub fn variable(&self, col: c_int) -> Option<Variable> {
+        if col < 1 || col > self.problem.num_cols() {
+            None
+        } else {
+            Some(Variable::from_column(col))
+        }
+    }
+
+    /// All variables of the model, in column order.
+    pub fn variables(&self) -> Vec<Variable> {
+        (1..=self.problem.num_cols()).map(Variable::from_column).collect()
+    }
+
+    /// Renames a variable.
+    ///
+    /// Panics if `name` contains a nul byte.
+    pub fn set_var_name(&mut self, var: Variable, name: &str) {
+        let name = CString::new(name).expect("variable names can't contain nul bytes");
+        assert!(self.problem.set_col_name(var.column(), &name), "no column {}", var.column());
+    }
+
+    /// The name of a variable, lpsolve's default `C<column>` if it was never named.
+    pub fn var_name(&self, var: Variable) -> String {
+        self.problem.get_col_name(var.column()).unwrap_or_else(|| panic!("no column {}", var.column()))
+    }
+
+    /// Adds a constraint, like `(x + y).le(10.0)`, as a new row.
+    pub fn add_constraint(&mut self, constraint: LinearConstraint) -> Constraint {
+        let (coeffs, indices) = scatter(constraint.terms());
+        assert!(self.problem.add_constraint_scatter(&coeffs, &indices, constraint.rhs(), constraint.kind()),
+                "lpsolve failed to add a row");
+        Constraint::from_row(self.problem.num_rows())
+    }
+
+    /// Adds a constraint and names its row.
+    ///
+    /// Panics if `name` contains a nul byte.
+    pub fn add_named_constraint(&mut self, name: &str, constraint: LinearConstraint) -> Constraint {
+        let row = self.add_constraint(constraint);
+        let name = CString::new(name).expect("constraint names can't contain nul bytes");
+        assert!(self.problem.set_row_name(row.row(), &name));
+        row
+    }
+
+    /// The name of a constraint, lpsolve's default `R<row>` if it was never named.
+    pub fn constraint_name(&self, constraint: Constraint) -> String {
+        self.problem.get_row_name(constraint.row()).unwrap_or_else(|| panic!("no row {}", constraint.row()))
+    }
+
+    /// The relation of a constraint.
+    pub fn constraint_type(&self, constraint: Constraint) -> ConstraintType {
+        self.problem.get_constraint_type(constraint.row()).unwrap_or_else(|| panic!("no row {}", constraint.row()))
+    }
+
+    /// Replaces the objective function with `expr`, to be minimized. Its constant becomes the
+    /// objective constant. The penalties of the goals are added to it.
+    pub fn minimize<E: Into<LinearExpr>>(&mut self, expr: E) {
+        self.problem.set_minim();
+        self.set_objective(expr.into());
+    }
+
+    /// Replaces the objective function with `expr`, to be maximized. Its constant becomes the
+    /// objective constant. The penalties of the goals are subtracted from it.
+    pub fn maximize<E: Into<LinearExpr>>(&mut self, expr: E) {
+        self.problem.set_maxim();
+        self.set_objective(expr.into());
+    }
+
+    /// Adds a special ordered set of `vars`, weighted by their position, at the lowest priority so
+    /// far.
+    ///
+    /// Panics if `name` contains a nul byte.
+    pub fn add_sos(&mut self, name: &str, sostype: SOSType, vars: &[Variable]) {
+        let name = CString::new(name).expect("SOS names can't contain nul bytes");
+        let priority = self.problem.sos_count() + 1;
+        let columns: Vec<_> = vars.iter().map(|var| var.column()).collect();
+        let weights: Vec<_> = (1..=vars.len()).map(|i| i as f64).collect();
+        assert!(self.problem.add_sos_constraint(&name, sostype, priority, &weights, &columns),
+                "lpsolve failed to add an SOS constraint");
+    }
+
+    /// Solves the model and takes a snapshot of the results.
+    pub fn solve(&mut self) -> Solution {
+        self.problem.solve();
+        Solution::from_problem(&self.problem)
+    }
+
+    fn set_objective(&mut self, mut expr: LinearExpr) {
+        let sign = self.penalty_sign();
+        for goal in &self.goals {
+            expr.add_term(goal.under, sign * goal.under_penalty);
+            expr.add_term(goal.over, sign * goal.over_penalty);
+        }
+        expr.simplify();
+        let (coeffs, indices) = scatter(expr.terms());
+        assert!(self.problem.scatter_objective_function(&coeffs, &indices), "lpsolve failed to set the objective");
+        assert!(self.problem.set_rh(0, expr.constant()));
+    }
+}
+
+impl Default for Model {
+    fn default() -> Model {
+        Model::new()
+    }
+}
+
+/// Splits merged terms into the coefficient and index arrays the scatter methods take.
+fn scatter(terms: &[(Variable, f64)]) -> (Vec<f64>, Vec<c_int>) {
+    terms.iter().map(|&(var, coeff)| (coeff, var.column())).unzip()
+}
+
+#[cfg(test)]
+mod tests {
+    use {ConstraintType, SolveStatus};
+    use modeling::sum;
+    use super::*;
+
+    #[test]
+    fn build_and_solve() {
+        let mut model = Model::new();
+        let x = model.add_var("x", VarKind::Continuous, 0.0..);
+        let y = model.add_var("y", VarKind::Integer, 0.0..=3.5);
+        let b = model.add_var("", VarKind::Binary, ..);
+        let c = model.add_named_constraint("cap", (x + y + x).le(10.0 - y));
+        model.add_constraint((x - 1.0).ge(b));
+        model.maximize(3.0 * x + 2.0 * y + 0.5);
+
+        assert_eq!(model.var_name(y), "y");
+        assert_eq!(model.var_name(b), "C3");
+        assert_eq!(model.constraint_name(c), "cap");
+        assert_eq!(model.constraint_type(c), ConstraintType::Le);
+        assert_eq!(model.problem().get_lower_bound(b.column()), Some(0.0));
+        assert_eq!(model.problem().get_upper_bound(b.column()), Some(1.0));
+        assert_eq!(model.problem().get_rh(c.row()), Some(10.0));
+        let mut row = [0.0; 4];
+        assert!(model.problem().get_row(&mut row, c.row()));
+        assert_eq!(row, [0.0, 2.0, 2.0, 0.0]);
+
+        assert_eq!(model.problem_mut().solve(), SolveStatus::Optimal);
+        assert_eq!(model.problem().get_objective(), 15.5);
+
+        // Replacing the objective clears the coefficients that aren't mentioned again.
+        model.minimize(y);
+        assert_eq!(model.problem_mut().solve(), SolveStatus::Optimal);
+        assert_eq!(model.problem().get_objective(), 0.0);
+    }
+
+    #[test]
+    fn indexed_families() {
+        let mut model = Model::new();
+        let pairs = (0..3).flat_map(|i| (0..2).map(move |j| (i, j)));
+        let x = model.add_vars("x", pairs, VarKind::Binary, ..);
+        let y = model.add_vars("", vec!["a", "b"], VarKind::Continuous, 1.0..=2.0);
+
+        assert_eq!(model.problem().num_cols(), 8);
+        assert_eq!(x[&(0, 0)].column(), 1);
+        assert_eq!(model.var_name(x[&(2, 1)]), "x_2_1");
+        assert_eq!(model.var_name(y["b"]), "C8");
+        assert_eq!(model.problem().get_upper_bound(x[&(1, 0)].column()), Some(1.0));
+
+        // Each i gets exactly one j.
+        for i in 0..3 {
+            model.add_constraint(sum((0..2).map(|j| x[&(i, j)])).eq(1));
+        }
+        model.maximize(sum(x.iter().map(|(&(i, j), &v)| f64::from(i * j) * v)) + sum(y.values()));
+        assert_eq!(model.problem_mut().solve(), SolveStatus::Optimal);
+        assert_eq!(model.problem().get_objective(), 7.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate index")]
+    fn duplicate_index() {
+        Model::new().add_vars("x", vec![1, 2, 1], VarKind::Continuous, ..);
+    }
+}
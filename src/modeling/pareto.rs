@@ -0,0 +1,266 @@
+//! Trade-off curves between several objectives.
+//!
+//! Both methods work on a clone of the model and return the non-dominated points they find, with
+//! repeated vertices removed:
+//!
+//! * `weighted_sum` optimizes weighted sums of the objectives for a grid of weights. It only
+//!   finds points on the convex hull of the front, but needs one solve per weight.
+//! * `epsilon_constraint` optimizes the first objective while each of the others is bounded by
+//!   a value swept across its range, moving the bounds with `set_rh` between solves. It also
+//!   finds points in the nonconvex parts of the front of an integer model.
+//!
+//! The range of each objective is estimated from its best and worst values over the solutions
+//! that optimize the objectives one at a time. The weighted sums are scaled by these ranges, so
+//! the weights don't depend on the units of the objectives.
+//!
+//! The objectives are set with `Model::minimize` and `Model::maximize`, so the penalties of any
+//! goals of the model are optimized along with each of them. The values reported in
+//! `ParetoPoint::objectives` are those of the objectives alone.
+//!
+//! ```
+//! use lpsolve::modeling::{Model, VarKind, Sense, pareto};
+//!
+//! let mut model = Model::new();
+//! let x = model.add_var("x", VarKind::Continuous, 0.0..);
+//! let y = model.add_var("y", VarKind::Continuous, 0.0..);
+//! model.add_constraint((x + 2.0 * y).le(8.0));
+//! model.add_constraint((2.0 * x + y).le(8.0));
+//!
+//! let objectives = [(x + 0.0, Sense::Maximize), (y + 0.0, Sense::Maximize)];
+//! let front = pareto::epsilon_constraint(&model, &objectives, 4);
+//! let points: Vec<_> = front.iter().map(|p| (p.objectives[0], p.objectives[1])).collect();
+//! assert_eq!(points, [(0.0, 4.0), (2.0, 3.0), (3.0, 2.0), (3.5, 1.0), (4.0, 0.0)]);
+//! ```
+
+use SolveStatus;
+use super::{LinearExpr, Model, Sense, Solution};
+
+/// A non-dominated solution and the value of each objective there.
+#[derive(Debug, Clone)]
+pub struct ParetoPoint {
+    pub objectives: Vec<f64>,
+    pub solution: Solution,
+}
+
+/// Relative tolerance under which two objective values count as equal.
+const EPSILON: f64 = 1e-9;
+
+fn same(a: f64, b: f64) -> bool {
+    (a - b).abs() <= EPSILON * (1.0 + a.abs().max(b.abs()))
+}
+
+/// Returns the solution if `model` solves to optimality.
+fn solve(model: &mut Model) -> Option<Solution> {
+    let solution = model.solve();
+    if solution.status() == SolveStatus::Optimal { Some(solution) } else { None }
+}
+
+/// The value of each objective at `solution`.
+fn evaluate(solution: &Solution, objectives: &[(LinearExpr, Sense)]) -> Vec<f64> {
+    objectives.iter().map(|(expr, _)| solution.evaluate(expr)).collect()
+}
+
+/// The best and the worst value of each objective over the solutions that optimize the
+/// objectives one at a time, or `None` if one of them has no optimum.
+fn payoff(model: &mut Model, objectives: &[(LinearExpr, Sense)]) -> Option<Vec<(f64, f64)>> {
+    let mut table = Vec::with_capacity(objectives.len());
+    for &(ref expr, sense) in objectives {
+        model.set_objective_sense(expr.clone(), sense);
+        table.push(evaluate(&solve(model)?, objectives));
+    }
+    Some(objectives.iter().enumerate().map(|(j, &(_, sense))| {
+        let values = table.iter().map(|row| row[j]);
+        let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| (lo.min(v), hi.max(v)));
+        match sense {
+            Sense::Minimize => (min, max),
+            Sense::Maximize => (max, min),
+        }
+    }).collect())
+}
+
+/// Whether `a` is at least as good as `b` in every objective and better in one.
+fn dominates(a: &[f64], b: &[f64], objectives: &[(LinearExpr, Sense)]) -> bool {
+    let mut better = false;
+    for ((&a, &b), &(_, sense)) in a.iter().zip(b).zip(objectives) {
+        if same(a, b) {
+            continue;
+        }
+        if (a < b) != (sense == Sense::Minimize) {
+            return false;
+        }
+        better = true;
+    }
+    better
+}
+
+/// Drops dominated and repeated points, and sorts the rest by their objective values.
+fn non_dominated(points: Vec<ParetoPoint>, objectives: &[(LinearExpr, Sense)]) -> Vec<ParetoPoint> {
+    let mut front: Vec<ParetoPoint> = Vec::with_capacity(points.len());
+    for point in points {
+        let repeated = |other: &ParetoPoint| other.objectives.iter().zip(&point.objectives).all(|(&a, &b)| same(a, b));
+        if front.iter().any(|other| repeated(other) || dominates(&other.objectives, &point.objectives, objectives)) {
+            continue;
+        }
+        front.retain(|other| !dominates(&point.objectives, &other.objectives, objectives));
+        front.push(point);
+    }
+    front.sort_by(|a, b| a.objectives.partial_cmp(&b.objectives).unwrap());
+    front
+}
+
+/// Calls `f` with every way of splitting `steps` into `parts` nonnegative integers.
+fn compositions<F: FnMut(&[usize])>(parts: usize, steps: usize, f: &mut F) {
+    fn go<F: FnMut(&[usize])>(prefix: &mut Vec<usize>, parts: usize, left: usize, f: &mut F) {
+        if prefix.len() + 1 == parts {
+            prefix.push(left);
+            f(prefix);
+            prefix.pop();
+            return;
+        }
+        for n in 0..=left {
+            prefix.push(n);
+            go(prefix, parts, left - n, f);
+            prefix.pop();
+        }
+    }
+    go(&mut Vec::with_capacity(parts), parts, steps, f)
+}
+
+/// Optimizes weighted sums of `objectives` for all weights that are multiples of `1 / steps`,
+/// and returns the non-dominated solutions.
+///
+/// Returns an empty front if an objective has no optimum on its own.
+///
+/// Panics if `objectives` is empty or `steps` is 0.
+pub fn weighted_sum(model: &Model, objectives: &[(LinearExpr, Sense)], steps: usize) -> Vec<ParetoPoint> {
+    assert!(!objectives.is_empty() && steps > 0, "a Pareto front needs objectives and steps");
+    let mut model = model.clone();
+    let ranges = match payoff(&mut model, objectives) {
+        Some(ranges) => ranges,
+        None => return Vec::new(),
+    };
+    let mut points = Vec::new();
+    compositions(objectives.len(), steps, &mut |parts| {
+        // Minimize the weighted sum of the objectives, each scaled to the range from 0 at its
+        // best to 1 at its worst.
+        let mut combined = LinearExpr::new();
+        for ((&part, (expr, _)), &(best, worst)) in parts.iter().zip(objectives).zip(&ranges) {
+            let span = if same(best, worst) { 1.0 } else { worst - best };
+            combined += expr.clone() * (part as f64 / steps as f64 / span);
+        }
+        model.minimize(combined);
+        if let Some(solution) = solve(&mut model) {
+            points.push(ParetoPoint { objectives: evaluate(&solution, objectives), solution });
+        }
+    });
+    non_dominated(points, objectives)
+}
+
+/// Optimizes the first of `objectives` while bounding each of the others by `steps + 1` values
+/// evenly spread across its range, and returns the non-dominated solutions.
+///
+/// With `n` objectives this takes up to `(steps + 1)^(n - 1)` solves. Returns an empty front if
+/// an objective has no optimum on its own.
+///
+/// Panics if `objectives` is empty or `steps` is 0.
+pub fn epsilon_constraint(model: &Model, objectives: &[(LinearExpr, Sense)], steps: usize) -> Vec<ParetoPoint> {
+    assert!(!objectives.is_empty() && steps > 0, "a Pareto front needs objectives and steps");
+    let mut model = model.clone();
+    let ranges = match payoff(&mut model, objectives) {
+        Some(ranges) => ranges,
+        None => return Vec::new(),
+    };
+
+    // One row per bounded objective, starting out at its worst value, so it doesn't cut anything
+    // off. The objective's constant is on the right hand side.
+    let mut rows = Vec::with_capacity(objectives.len() - 1);
+    for (&(ref expr, sense), &(_, worst)) in objectives.iter().zip(&ranges).skip(1) {
+        let row = match sense {
+            Sense::Minimize => model.add_constraint(expr.clone().le(worst)),
+            Sense::Maximize => model.add_constraint(expr.clone().ge(worst)),
+        };
+        rows.push((row, expr.constant()));
+    }
+    let (ref first, sense) = objectives[0];
+    model.set_objective_sense(first.clone(), sense);
+
+    let mut points = Vec::new();
+    let mut grid = vec![0; rows.len()];
+    loop {
+        for ((&(row, constant), &(best, worst)), &step) in rows.iter().zip(&ranges[1..]).zip(&grid) {
+            let bound = worst + (best - worst) * step as f64 / steps as f64;
+            assert!(model.problem_mut().set_rh(row.row(), bound - constant));
+        }
+        if let Some(solution) = solve(&mut model) {
+            points.push(ParetoPoint { objectives: evaluate(&solution, objectives), solution });
+        }
+        // Advance the grid like an odometer.
+        match grid.iter().position(|&step| step < steps) {
+            Some(i) => {
+                grid[i] += 1;
+                for step in &mut grid[..i] {
+                    *step = 0;
+                }
+            }
+            None => break,
+        }
+    }
+    non_dominated(points, objectives)
+}
+
+#[cfg(test)]
+mod tests {
+    use modeling::{LinearExpr, Model, Sense, VarKind};
+    use super::*;
+
+    #[test]
+    fn front_of_a_polytope() {
+        // The front runs from (0, 6) over (2, 4) to (14/3, 0).
+        let mut model = Model::new();
+        let x = model.add_var("x", VarKind::Continuous, 0.0..=5.0);
+        let y = model.add_var("y", VarKind::Continuous, 0.0..=6.0);
+        model.add_constraint((x + y).le(6.0));
+        model.add_constraint((3.0 * x + 2.0 * y).le(14.0));
+        let objectives = [(LinearExpr::from(x), Sense::Maximize), (y - 0.0, Sense::Maximize)];
+
+        let front = weighted_sum(&model, &objectives, 10);
+        let expected = [[0.0, 6.0], [2.0, 4.0], [14.0 / 3.0, 0.0]];
+        assert_eq!(front.len(), expected.len());
+        for (point, expected) in front.iter().zip(&expected) {
+            assert!(point.objectives.iter().zip(expected).all(|(&a, &b)| (a - b).abs() < 1e-9));
+        }
+        for point in &front {
+            assert_eq!(point.objectives, [point.solution[x], point.solution[y]]);
+        }
+
+        let front = epsilon_constraint(&model, &objectives, 6);
+        assert_eq!(front.len(), 7);
+        assert!(front.windows(2).all(|w| w[0].objectives[0] < w[1].objectives[0]
+                                         && w[0].objectives[1] > w[1].objectives[1]));
+    }
+
+    #[test]
+    fn nonconvex_integer_front() {
+        // Only the middle point is missed by the weighted sums, because it lies below the line
+        // between the other two.
+        let mut model = Model::new();
+        let pick = model.add_vars("pick", 0..3, VarKind::Binary, ..);
+        model.add_constraint((pick[&0] + pick[&1] + pick[&2]).eq(1.0));
+        let cost = 0.0 * pick[&0] + 4.0 * pick[&1] + 10.0 * pick[&2];
+        let quality = 0.0 * pick[&0] + 3.0 * pick[&1] + 10.0 * pick[&2];
+        let objectives = [(cost, Sense::Minimize), (quality, Sense::Maximize)];
+
+        assert_eq!(weighted_sum(&model, &objectives, 20).len(), 2);
+        let front = epsilon_constraint(&model, &objectives, 10);
+        let vertices: Vec<_> = front.iter().map(|p| p.objectives.clone()).collect();
+        assert_eq!(vertices, [vec![0.0, 0.0], vec![4.0, 3.0], vec![10.0, 10.0]]);
+    }
+
+    #[test]
+    fn dominance() {
+        let objectives = [(LinearExpr::new(), Sense::Minimize), (LinearExpr::new(), Sense::Maximize)];
+        assert!(dominates(&[1.0, 5.0], &[2.0, 5.0], &objectives));
+        assert!(!dominates(&[1.0, 5.0], &[1.0, 5.0], &objectives));
+        assert!(!dominates(&[1.0, 4.0], &[2.0, 5.0], &objectives));
+    }
+}
@@ -0,0 +1,77 @@
+use SOSType;
+use super::{sum, LinearExpr, Model, VarKind, Variable};
+
+impl Model {
+    /// Adds a variable `y` that equals the piecewise-linear function through `breakpoints` at
+    /// `x`, and returns it.
+    ///
+    /// The breakpoints are `(x, y)` pairs with strictly increasing `x`. Outside of them the
+    /// function is undefined, so `x` is kept between the first and the last. The function may be
+    /// nonconvex, as for a cost curve with volume discounts: `y` is written as a convex
+    /// combination of the breakpoints, with weights in a new SOS2 set so that at most two
+    /// neighbouring breakpoints take part.
+    ///
+    /// Panics if there are fewer than two breakpoints or they aren't sorted.
+    pub fn add_piecewise_linear<E: Into<LinearExpr>>(&mut self, x: E, breakpoints: &[(f64, f64)]) -> Variable {
+        assert!(breakpoints.len() >= 2, "a piecewise-linear function needs at least two breakpoints");
+        assert!(breakpoints.windows(2).all(|w| w[0].0 < w[1].0),
+                "the breakpoints of a piecewise-linear function must have strictly increasing x");
+
+        let y = self.add_var("", VarKind::Continuous, ..);
+        let weights: Vec<Variable> = breakpoints.iter()
+            .map(|_| self.add_var("", VarKind::Continuous, 0.0..=1.0))
+            .collect();
+        self.add_constraint(sum(&weights).eq(1.0));
+        let combine = |f: fn(&(f64, f64)) -> f64| {
+            sum(weights.iter().zip(breakpoints).map(|(&w, p)| f(p) * w))
+        };
+        self.add_constraint(combine(|p| p.0).eq(x));
+        self.add_constraint(combine(|p| p.1).eq(y));
+
+        self.add_sos(&format!("pwl_{}", y.column()), SOSType::Type2, &weights);
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use SolveStatus;
+    use modeling::{Model, VarKind};
+
+    // 1 per unit up to 10, then 0.5 up to 20, then 0.3 up to 30.
+    const DISCOUNTS: &[(f64, f64)] = &[(0.0, 0.0), (10.0, 10.0), (20.0, 15.0), (30.0, 18.0)];
+
+    #[test]
+    fn volume_discounts() {
+        let mut model = Model::new();
+        let x = model.add_var("x", VarKind::Continuous, 0.0..);
+        let cost = model.add_piecewise_linear(x, DISCOUNTS);
+        let c = model.add_constraint((1.0 * x).ge(15.0));
+        model.minimize(cost);
+        let solution = model.solve();
+        assert_eq!(solution.status(), SolveStatus::Optimal);
+        assert!((solution[cost] - 12.5).abs() < 1e-9);
+
+        // The function is concave, so without the SOS2 set lpsolve would mix the first and last
+        // breakpoints, giving a cost of 9 at x = 15.
+        assert!(model.problem_mut().set_rh(c.row(), 25.0));
+        let solution = model.solve();
+        assert!((solution[x] - 25.0).abs() < 1e-9);
+        assert!((solution[cost] - 16.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn of_an_expression() {
+        let mut model = Model::new();
+        let a = model.add_var("a", VarKind::Integer, 0.0..=10.0);
+        let b = model.add_var("b", VarKind::Integer, 0.0..=10.0);
+        let cost = model.add_piecewise_linear(a + b, DISCOUNTS);
+        model.add_constraint((a - b).eq(3.0));
+        // With a price of 0.8 per unit, buying only pays off in bulk.
+        model.maximize(0.8 * a + 0.8 * b - cost);
+        let solution = model.solve();
+        assert_eq!(solution.status(), SolveStatus::Optimal);
+        assert_eq!((solution[a], solution[b]), (10.0, 7.0));
+        assert!((solution[cost] - 13.5).abs() < 1e-9);
+    }
+}
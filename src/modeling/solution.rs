@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::ops::Index;
+
+use {Problem, SolutionData, SolveStatus};
+use super::{Constraint, LinearExpr, Variable};
+
+/// The results of `Model::solve`, detached from the model.
+///
+/// A `Solution` owns copies of everything it reports, so it stays valid when the model is changed
+/// or dropped. Values are looked up by `Variable`, by `Constraint` or by name, and indexing with
+/// `solution[x]` gives the value of a variable, `solution[c]` the activity of a constraint.
+#[derive(Debug, Clone)]
+pub struct Solution {
+    data: SolutionData,
+    columns: HashMap<String, Variable>,
+    rows: HashMap<String, Constraint>,
+}
+
+impl Solution {
+    /// Takes a snapshot of the most recent solution of `problem`, along with the names of its
+    /// columns and rows.
+    pub fn from_problem(problem: &Problem) -> Solution {
+        let columns = (1..=problem.num_cols())
+            .filter_map(|col| problem.get_col_name(col).map(|name| (name, Variable::from_column(col))))
+            .collect();
+        let rows = (1..=problem.num_rows())
+            .filter_map(|row| problem.get_row_name(row).map(|name| (name, Constraint::from_row(row))))
+            .collect();
+        Solution { data: SolutionData::from_problem(problem), columns, rows }
+    }
+
+    /// How the solve ended. The values are only meaningful if a feasible solution was found.
+    pub fn status(&self) -> SolveStatus {
+        self.data.status
+    }
+
+    /// The value of the objective function.
+    pub fn objective(&self) -> f64 {
+        self.data.objective
+    }
+
+    /// The value of a variable.
+    ///
+    /// Panics if the variable didn't exist when the model was solved, as do the other lookups by
+    /// `Variable` or `Constraint`.
+    pub fn value(&self, var: Variable) -> f64 {
+        self.data.variables[column_index(var)]
+    }
+
+    /// The value of a constraint's left hand side.
+    pub fn activity(&self, constraint: Constraint) -> f64 {
+        self.data.constraints[row_index(constraint)]
+    }
+
+    /// The dual value of a constraint, if lpsolve could provide duals.
+    pub fn dual(&self, constraint: Constraint) -> Option<f64> {
+        self.data.duals.as_ref().map(|duals| duals[row_index(constraint)])
+    }
+
+    /// The reduced cost of a variable, if lpsolve could provide duals.
+    pub fn reduced_cost(&self, var: Variable) -> Option<f64> {
+        self.data.reduced_costs.as_ref().map(|costs| costs[column_index(var)])
+    }
+
+    /// The value of `expr` at this solution.
+    pub fn evaluate(&self, expr: &LinearExpr) -> f64 {
+        expr.evaluate(|var| self.value(var))
+    }
+
+    /// The variable with the given name, which is `C<column>` for columns that were never named.
+    pub fn variable(&self, name: &str) -> Option<Variable> {
+        self.columns.get(name).copied()
+    }
+
+    /// The constraint with the given name, which is `R<row>` for rows that were never named.
+    pub fn constraint(&self, name: &str) -> Option<Constraint> {
+        self.rows.get(name).copied()
+    }
+
+    /// The value of the variable with the given name.
+    pub fn value_by_name(&self, name: &str) -> Option<f64> {
+        self.variable(name).map(|var| self.value(var))
+    }
+
+    /// The activity of the constraint with the given name.
+    pub fn activity_by_name(&self, name: &str) -> Option<f64> {
+        self.constraint(name).map(|constraint| self.activity(constraint))
+    }
+
+    /// The plain values, in column and row order.
+    pub fn data(&self) -> &SolutionData {
+        &self.data
+    }
+
+    /// Unwraps the plain values.
+    pub fn into_data(self) -> SolutionData {
+        self.data
+    }
+}
+
+fn column_index(var: Variable) -> usize {
+    assert!(var.column() >= 1, "no column {}", var.column());
+    var.column() as usize - 1
+}
+
+fn row_index(constraint: Constraint) -> usize {
+    assert!(constraint.row() >= 1, "no row {}", constraint.row());
+    constraint.row() as usize - 1
+}
+
+impl Index<Variable> for Solution {
+    type Output = f64;
+
+    fn index(&self, var: Variable) -> &f64 {
+        &self.data.variables[column_index(var)]
+    }
+}
+
+impl Index<Constraint> for Solution {
+    type Output = f64;
+
+    fn index(&self, constraint: Constraint) -> &f64 {
+        &self.data.constraints[row_index(constraint)]
+    }
+}
+
+impl Index<&str> for Solution {
+    type Output = f64;
+
+    /// The value of the variable with the given name. Panics if there is none.
+    fn index(&self, name: &str) -> &f64 {
+        match self.variable(name) {
+            Some(var) => &self[var],
+            None => panic!("no variable named {:?}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use SolveStatus;
+    use modeling::{Model, VarKind};
+
+    #[test]
+    fn lookups() {
+        let mut model = Model::new();
+        let x = model.add_var("x", VarKind::Continuous, 0.0..);
+        let y = model.add_var("y", VarKind::Continuous, 0.0..);
+        let land = model.add_named_constraint("land", (120.0 * x + 210.0 * y).le(15000.0));
+        let labor = model.add_constraint((110.0 * x + 30.0 * y).le(4000.0));
+        let acres = model.add_constraint((x + y).le(75.0));
+        model.maximize(143.0 * x + 60.0 * y);
+
+        let solution = model.solve();
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        assert_eq!(solution.status(), SolveStatus::Optimal);
+        assert!(close(solution.objective(), 6315.625));
+        assert!(close(solution[x], 21.875) && close(solution["y"], 53.125));
+        assert!(close(solution.value_by_name("x").unwrap(), 21.875));
+        assert!(close(solution[acres], 75.0));
+        assert_eq!(solution.activity_by_name("land"), Some(solution.activity(land)));
+        assert_eq!(solution.constraint("R2"), Some(labor));
+        assert!(close(solution.dual(labor).unwrap(), 1.0375));
+        assert!(close(solution.dual(acres).unwrap(), 28.875));
+        assert_eq!(solution.dual(land), Some(0.0));
+        assert_eq!(solution.reduced_cost(x), Some(0.0));
+        assert!(close(solution.evaluate(&(x - y)), -31.25));
+        assert_eq!(solution.variable("z"), None);
+
+        // The solution doesn't change along with the model.
+        model.add_constraint((1.0 * y).le(10.0));
+        assert!(model.solve().objective() < solution.objective());
+        drop(model);
+        assert!(close(solution[y], 53.125));
+    }
+}
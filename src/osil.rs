@@ -0,0 +1,446 @@
+//! Reading and writing OSiL, the Optimization Services instance language.
+//!
+//! OSiL is an XML format. This module handles the linear part of it: `variables` with their
+//! types and bounds, the first objective of `objectives`, `constraints` with their bounds, and the
+//! `linearConstraintCoefficients` in sparse column-major (`rowIdx`) or row-major (`colIdx`) form.
+//! Array elements may use the `mult` and `incr` attributes. Quadratic and nonlinear parts of an
+//! instance are rejected rather than ignored.
+//!
+//! OSiL gives a constraint a lower and an upper bound rather than a type, so a constraint with two
+//! finite, different bounds is read as a `<=` row with a range. A snapshot with special ordered
+//! sets can't be written, since the format has no place for them.
+
+use std::io::{self, Read, Write};
+
+use libc::c_int;
+use roxmltree::{Document, Node};
+
+use {ColumnData, ConstraintType, ModelData, Problem, RowData};
+use lp_format::{ParseError, Span};
+
+const INFINITY: f64 = 1e30;
+
+fn span(doc: &Document, node: Node) -> Span {
+    let range = node.range();
+    let pos = doc.text_pos_at(range.start);
+    Span { start: range.start, end: range.end, line: pos.row as usize, column: pos.col as usize }
+}
+
+fn error<T>(doc: &Document, node: Node, message: String) -> Result<T, ParseError> {
+    Err(ParseError::new(message, span(doc, node)))
+}
+
+fn number(doc: &Document, node: Node, text: &str) -> Result<f64, ParseError> {
+    match text.trim() {
+        "INF" | "Infinity" => Ok(INFINITY),
+        "-INF" | "-Infinity" => Ok(-INFINITY),
+        text => match text.parse::<f64>() {
+            Ok(value) => Ok(value.clamp(-INFINITY, INFINITY)),
+            Err(_) => error(doc, node, format!("invalid number `{}`", text)),
+        },
+    }
+}
+
+fn attribute(doc: &Document, node: Node, name: &str, default: f64) -> Result<f64, ParseError> {
+    match node.attribute(name) {
+        Some(text) => number(doc, node, text),
+        None => Ok(default),
+    }
+}
+
+fn count(doc: &Document, node: Node, name: &str) -> Result<Option<usize>, ParseError> {
+    match node.attribute(name) {
+        Some(text) => match text.trim().parse() {
+            Ok(n) => Ok(Some(n)),
+            Err(_) => error(doc, node, format!("invalid `{}`", name)),
+        },
+        None => Ok(None),
+    }
+}
+
+fn children<'a, 'input>(node: Node<'a, 'input>, name: &'a str) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.children().filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &'a str) -> Option<Node<'a, 'input>> {
+    children(node, name).next()
+}
+
+/// Reads the `el` children of an array, expanding `mult` and `incr`, into at most `max` entries.
+fn elements(doc: &Document, array: Node, max: usize) -> Result<Vec<f64>, ParseError> {
+    let mut values = Vec::new();
+    for el in children(array, "el") {
+        let value = number(doc, el, el.text().unwrap_or(""))?;
+        let mult = count(doc, el, "mult")?.unwrap_or(1);
+        let incr = attribute(doc, el, "incr", 0.0)?;
+        if mult > max - values.len() {
+            return error(doc, el, format!("`{}` has more than {} entries", array.tag_name().name(), max));
+        }
+        values.extend((0..mult).map(|i| value + i as f64 * incr));
+    }
+    Ok(values)
+}
+
+fn index(doc: &Document, node: Node, value: f64, len: usize, what: &str) -> Result<usize, ParseError> {
+    if value >= 0.0 && value.fract() == 0.0 && (value as usize) < len {
+        Ok(value as usize)
+    } else {
+        error(doc, node, format!("{} index {} is out of range", what, value))
+    }
+}
+
+/// Parse an OSiL instance.
+pub fn parse(src: &str) -> Result<ModelData, ParseError> {
+    let doc = Document::parse(src).map_err(|e| {
+        let pos = e.pos();
+        ParseError::new(e.to_string(), Span { line: pos.row as usize, column: pos.col as usize, ..Span::default() })
+    })?;
+    let root = doc.root_element();
+    if root.tag_name().name() != "osil" {
+        return error(&doc, root, "expected an `osil` element".to_string());
+    }
+    let name = child(root, "instanceHeader").and_then(|h| child(h, "name")).and_then(|n| n.text())
+        .unwrap_or("").trim().to_string();
+    let data = match child(root, "instanceData") {
+        Some(data) => data,
+        None => return error(&doc, root, "missing `instanceData`".to_string()),
+    };
+    for unsupported in &["quadraticCoefficients", "nonlinearExpressions"] {
+        if let Some(node) = child(data, unsupported) {
+            return error(&doc, node, format!("`{}` is not supported", unsupported));
+        }
+    }
+
+    let mut columns = Vec::new();
+    if let Some(variables) = child(data, "variables") {
+        for var in children(variables, "var") {
+            let kind = var.attribute("type").unwrap_or("C");
+            let (integer, semicontinuous) = match kind {
+                "C" => (false, false),
+                "I" | "B" => (true, false),
+                "D" => (false, true),
+                "J" => (true, true),
+                _ => return error(&doc, var, format!("unsupported variable type `{}`", kind)),
+            };
+            let upper = if kind == "B" { 1.0 } else { INFINITY };
+            columns.push(ColumnData {
+                name: var.attribute("name").map(|s| s.to_string()),
+                lower: attribute(&doc, var, "lb", 0.0)?,
+                upper: attribute(&doc, var, "ub", upper)?,
+                integer,
+                semicontinuous,
+            });
+        }
+        if count(&doc, variables, "numberOfVariables")?.map_or(false, |n| n != columns.len()) {
+            return error(&doc, variables, "`numberOfVariables` doesn't match the number of `var` elements".to_string());
+        }
+    }
+
+    let (mut maximize, mut objective, mut objective_constant) = (false, Vec::new(), 0.0);
+    if let Some(objectives) = child(data, "objectives") {
+        let mut all = children(objectives, "obj");
+        if let Some(obj) = all.next() {
+            maximize = obj.attribute("maxOrMin").unwrap_or("min") == "max";
+            objective_constant = attribute(&doc, obj, "constant", 0.0)?;
+            for coef in children(obj, "coef") {
+                let idx = count(&doc, coef, "idx")?.unwrap_or(usize::MAX);
+                let col = index(&doc, coef, idx as f64, columns.len(), "variable")?;
+                objective.push((col as c_int + 1, number(&doc, coef, coef.text().unwrap_or(""))?));
+            }
+        }
+        if let Some(obj) = all.next() {
+            return error(&doc, obj, "only one objective is supported".to_string());
+        }
+    }
+
+    let mut rows = Vec::new();
+    if let Some(constraints) = child(data, "constraints") {
+        for con in children(constraints, "con") {
+            let constant = attribute(&doc, con, "constant", 0.0)?;
+            let shift = |bound: f64| if bound.abs() < INFINITY { bound - constant } else { bound };
+            let lower = shift(attribute(&doc, con, "lb", -INFINITY)?);
+            let upper = shift(attribute(&doc, con, "ub", INFINITY)?);
+            let (kind, rhs, range) = if lower == upper {
+                (ConstraintType::Eq, upper, None)
+            } else if upper < INFINITY {
+                (ConstraintType::Le, upper, if lower > -INFINITY { Some(upper - lower) } else { None })
+            } else {
+                (ConstraintType::Ge, lower, None)
+            };
+            rows.push(RowData { name: con.attribute("name").map(|s| s.to_string()), coefficients: Vec::new(), kind,
+                                rhs, range });
+        }
+    }
+
+    if let Some(matrix) = child(data, "linearConstraintCoefficients") {
+        // A matrix can't have more values than it has entries, whatever `numberOfValues` says.
+        let size = count(&doc, matrix, "numberOfValues")?.unwrap_or(usize::MAX)
+            .min(rows.len().saturating_mul(columns.len()));
+        let array = |name, max| child(matrix, name).map(|node| elements(&doc, node, max)).unwrap_or_else(|| Ok(Vec::new()));
+        let values = array("value", size)?;
+        let indices = (child(matrix, "rowIdx"), child(matrix, "colIdx"));
+        let (major, major_len, minor, minor_len, column_major) = match indices {
+            (Some(idx), _) => ("variable", columns.len(), elements(&doc, idx, size)?, rows.len(), true),
+            (None, Some(idx)) => ("constraint", rows.len(), elements(&doc, idx, size)?, columns.len(), false),
+            (None, None) => return error(&doc, matrix, "missing `rowIdx` or `colIdx`".to_string()),
+        };
+        let start = array("start", major_len + 1)?.into_iter()
+            .map(|s| index(&doc, matrix, s, values.len() + 1, "`start`"))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Without values, `start` may be left out or cut short, but otherwise it needs an entry for
+        // every variable or constraint and one past the end.
+        let complete = values.is_empty() || (start.len() == major_len + 1 && start[0] == 0);
+        if minor.len() != values.len() || !complete || start.last().map_or(false, |&s| s != values.len()) {
+            return error(&doc, matrix, "the sizes of `start`, the index array and `value` don't match".to_string());
+        }
+        for (i, bounds) in start.windows(2).enumerate() {
+            let (from, to) = (bounds[0], bounds[1]);
+            if from > to || to > values.len() {
+                return error(&doc, matrix, format!("`start` of {} {} is out of order", major, i));
+            }
+            for k in from..to {
+                let j = index(&doc, matrix, minor[k], minor_len, if column_major { "constraint" } else { "variable" })?;
+                let (row, col) = if column_major { (j, i) } else { (i, j) };
+                rows[row].coefficients.push((col as c_int + 1, values[k]));
+            }
+        }
+        for row in &mut rows {
+            row.coefficients.sort_by_key(|&(col, _)| col);
+        }
+    }
+
+    Ok(ModelData { name, maximize, objective, objective_constant, infinity: INFINITY, rows, columns, sos: Vec::new() })
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn format(value: f64, infinity: f64) -> String {
+    if value >= infinity {
+        "INF".to_string()
+    } else if value <= -infinity {
+        "-INF".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Write a model as an OSiL instance.
+///
+/// Fails with `InvalidInput` if the model has special ordered sets.
+pub fn write(data: &ModelData, out: &mut dyn Write) -> io::Result<()> {
+    if !data.sos.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "OSiL can't hold special ordered sets"));
+    }
+    let inf = data.infinity;
+    writeln!(out, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+    writeln!(out, "<osil xmlns=\"os.optimizationservices.org\">")?;
+    writeln!(out, "  <instanceHeader>")?;
+    writeln!(out, "    <name>{}</name>", escape(&data.name))?;
+    writeln!(out, "  </instanceHeader>")?;
+    writeln!(out, "  <instanceData>")?;
+
+    writeln!(out, "    <variables numberOfVariables=\"{}\">", data.columns.len())?;
+    for col in &data.columns {
+        let kind = match (col.integer, col.semicontinuous) {
+            (true, false) if col.lower == 0.0 && col.upper == 1.0 => "B",
+            (true, false) => "I",
+            (false, true) => "D",
+            (true, true) => "J",
+            (false, false) => "C",
+        };
+        write!(out, "      <var")?;
+        if let Some(ref name) = col.name {
+            write!(out, " name=\"{}\"", escape(name))?;
+        }
+        writeln!(out, " type=\"{}\" lb=\"{}\" ub=\"{}\"/>", kind, format(col.lower, inf), format(col.upper, inf))?;
+    }
+    writeln!(out, "    </variables>")?;
+
+    writeln!(out, "    <objectives numberOfObjectives=\"1\">")?;
+    writeln!(out, "      <obj maxOrMin=\"{}\" constant=\"{}\" numberOfObjCoef=\"{}\">",
+             if data.maximize { "max" } else { "min" }, format(data.objective_constant, inf), data.objective.len())?;
+    for &(col, value) in &data.objective {
+        writeln!(out, "        <coef idx=\"{}\">{}</coef>", col - 1, format(value, inf))?;
+    }
+    writeln!(out, "      </obj>")?;
+    writeln!(out, "    </objectives>")?;
+
+    writeln!(out, "    <constraints numberOfConstraints=\"{}\">", data.rows.len())?;
+    for row in &data.rows {
+        let (lower, upper) = match row.kind {
+            ConstraintType::Le => (row.range.map_or(-inf, |r| row.rhs - r), row.rhs),
+            ConstraintType::Ge => (row.rhs, row.range.map_or(inf, |r| row.rhs + r)),
+            ConstraintType::Eq => (row.rhs, row.rhs),
+            ConstraintType::Free => (-inf, inf),
+        };
+        write!(out, "      <con")?;
+        if let Some(ref name) = row.name {
+            write!(out, " name=\"{}\"", escape(name))?;
+        }
+        if lower > -inf {
+            write!(out, " lb=\"{}\"", format(lower, inf))?;
+        }
+        if upper < inf {
+            write!(out, " ub=\"{}\"", format(upper, inf))?;
+        }
+        writeln!(out, "/>")?;
+    }
+    writeln!(out, "    </constraints>")?;
+
+    let mut by_column = vec![Vec::new(); data.columns.len()];
+    for (i, row) in data.rows.iter().enumerate() {
+        for &(col, value) in &row.coefficients {
+            by_column[col as usize - 1].push((i, value));
+        }
+    }
+    let total: usize = by_column.iter().map(|c| c.len()).sum();
+    writeln!(out, "    <linearConstraintCoefficients numberOfValues=\"{}\">", total)?;
+    let mut start = 0;
+    writeln!(out, "      <start>")?;
+    writeln!(out, "        <el>0</el>")?;
+    for col in &by_column {
+        start += col.len();
+        writeln!(out, "        <el>{}</el>", start)?;
+    }
+    writeln!(out, "      </start>")?;
+    writeln!(out, "      <rowIdx>")?;
+    for &(row, _) in by_column.iter().flatten() {
+        writeln!(out, "        <el>{}</el>", row)?;
+    }
+    writeln!(out, "      </rowIdx>")?;
+    writeln!(out, "      <value>")?;
+    for &(_, value) in by_column.iter().flatten() {
+        writeln!(out, "        <el>{}</el>", format(value, inf))?;
+    }
+    writeln!(out, "      </value>")?;
+    writeln!(out, "    </linearConstraintCoefficients>")?;
+    writeln!(out, "  </instanceData>")?;
+    writeln!(out, "</osil>")
+}
+
+impl Problem {
+    /// Read a linear or integer OSiL instance.
+    ///
+    /// See the `osil` module for what part of the format is supported.
+    pub fn read_osil(input: &mut dyn Read) -> Result<Problem, ParseError> {
+        let mut src = String::new();
+        input.read_to_string(&mut src)
+            .map_err(|e| ParseError::new(format!("could not read the instance: {}", e), Span::default()))?;
+        parse(&src)?.to_problem()
+            .ok_or_else(|| ParseError::new("lpsolve rejected the model".to_string(), Span::default()))
+    }
+
+    /// Write the model as an OSiL instance.
+    ///
+    /// Returns `false` if writing fails or the model has special ordered sets.
+    pub fn write_osil(&self, out: &mut dyn Write) -> bool {
+        write(&ModelData::from_problem(self), out).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use {ConstraintType, Problem};
+    use super::parse;
+
+    fn example() -> Problem {
+        let mut lp = Problem::new(0, 4).unwrap();
+        lp.set_maxim();
+        assert!(lp.scatter_objective_function(&[143.0, 60.0, 1.0, -0.5], &[1, 2, 3, 4]));
+        assert!(lp.add_constraint_scatter(&[120.0, 210.0], &[1, 2], 15000.0, ConstraintType::Le));
+        assert!(lp.add_constraint_scatter(&[110.0, 30.0, 1.0], &[1, 2, 4], 4000.0, ConstraintType::Ge));
+        assert!(lp.add_constraint_scatter(&[1.0, 1.0, 1.0], &[1, 2, 3], 75.0, ConstraintType::Le));
+        assert!(lp.set_constraint_range(3, 10.0));
+        assert!(lp.add_constraint_scatter(&[1.0], &[4], 2.0, ConstraintType::Eq));
+        assert!(lp.set_row_name(1, &CString::new("land & water").unwrap()));
+        assert!(lp.set_col_name(2, &CString::new("barley").unwrap()));
+        assert!(lp.set_bounds(3, 0.0, 5.0));
+        assert!(lp.set_integer(3, true));
+        assert!(lp.set_bounds(4, -1e30, 1e30));
+        assert!(lp.set_rh(0, 3.0));
+        lp
+    }
+
+    fn mps_text(lp: &Problem) -> String {
+        let mut out = Vec::new();
+        assert!(lp.write_freemps(&mut out));
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let lp = example();
+        let mut out = Vec::new();
+        assert!(lp.write_osil(&mut out));
+        let copy = Problem::read_osil(&mut &out[..]).unwrap();
+        assert_eq!(mps_text(&copy), mps_text(&lp));
+    }
+
+    #[test]
+    fn compressed_arrays() {
+        let data = parse(r#"<?xml version="1.0"?>
+            <osil xmlns="os.optimizationservices.org">
+              <instanceHeader><name>small</name></instanceHeader>
+              <instanceData>
+                <variables numberOfVariables="3">
+                  <var name="x" lb="-INF"/><var type="B"/><var type="I" ub="10"/>
+                </variables>
+                <objectives numberOfObjectives="1">
+                  <obj maxOrMin="max" numberOfObjCoef="2"><coef idx="0">1</coef><coef idx="2">2.5</coef></obj>
+                </objectives>
+                <constraints numberOfConstraints="2">
+                  <con name="c0" ub="4"/><con lb="1" ub="3"/>
+                </constraints>
+                <linearConstraintCoefficients numberOfValues="4">
+                  <start><el mult="3" incr="2">0</el></start>
+                  <colIdx><el mult="2" incr="1">0</el><el>0</el><el>2</el></colIdx>
+                  <value><el mult="4">1</el></value>
+                </linearConstraintCoefficients>
+              </instanceData>
+            </osil>"#).unwrap();
+        assert_eq!(data.name, "small");
+        assert_eq!((data.columns[0].lower, data.columns[1].upper, data.columns[2].upper), (-1e30, 1.0, 10.0));
+        assert_eq!(data.objective, vec![(1, 1.0), (3, 2.5)]);
+        assert_eq!(data.rows[0].coefficients, vec![(1, 1.0), (2, 1.0)]);
+        assert_eq!(data.rows[1].coefficients, vec![(1, 1.0), (3, 1.0)]);
+        assert_eq!((data.rows[1].kind, data.rows[1].rhs, data.rows[1].range), (ConstraintType::Le, 3.0, Some(2.0)));
+    }
+
+    #[test]
+    fn errors() {
+        let e = parse("<osil>\n<instanceData>\n<variables numberOfVariables=\"2\"><var/></variables>\n\
+                       </instanceData></osil>").unwrap_err();
+        assert_eq!(e.span.line, 3);
+        let e = parse("<osil><instanceData>\n<objectives><obj><coef idx=\"3\">1</coef></obj></objectives>\n\
+                       </instanceData></osil>").unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 18: variable index 3 is out of range");
+        assert!(parse("<osil><instanceData>").is_err());
+    }
+
+    #[test]
+    fn mismatched_start() {
+        let matrix = |start: &str| parse(&format!(r#"<osil><instanceData>
+            <variables numberOfVariables="2"><var/><var/></variables>
+            <constraints numberOfConstraints="2"><con ub="1"/><con ub="2"/></constraints>
+            <linearConstraintCoefficients numberOfValues="2">
+              <start>{}</start><colIdx><el>0</el><el>1</el></colIdx><value><el mult="2">1</el></value>
+            </linearConstraintCoefficients>
+            </instanceData></osil>"#, start));
+        let message = "line 4, column 13: the sizes of `start`, the index array and `value` don't match";
+        // Empty, starting past the first value, and one entry short.
+        for start in &["", "<el>1</el><el>2</el><el>2</el>", "<el>0</el><el>2</el>"] {
+            assert_eq!(matrix(start).unwrap_err().to_string(), message, "{}", start);
+        }
+        let data = matrix("<el>0</el><el>1</el><el>2</el>").unwrap();
+        assert_eq!((&data.rows[0].coefficients[..], &data.rows[1].coefficients[..]), (&[(1, 1.0)][..], &[(2, 1.0)][..]));
+        for (start, value) in &[("<el>0</el><el>0.5</el><el>2</el>", "0.5"), ("<el>0</el><el>-1</el><el>2</el>", "-1")] {
+            assert_eq!(matrix(start).unwrap_err().message, format!("`start` index {} is out of range", value));
+        }
+        assert_eq!(matrix("<el mult=\"1000000000000\">0</el>").unwrap_err().to_string(),
+                   "line 5, column 22: `start` has more than 3 entries");
+    }
+}
@@ -0,0 +1,199 @@
+//! Feasibility relaxation.
+//!
+//! `Problem::feasibility_relaxation` finds the closest thing an infeasible problem has to a
+//! solution: one that violates a chosen set of rows and bounds as little as possible, as measured
+//! by a weight per unit of violation.
+
+use libc::c_int;
+
+use {ConstraintType, Problem, SolutionData, SolveStatus};
+
+/// A row or bound that `Problem::feasibility_relaxation` may violate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Relaxable {
+    Row(c_int),
+    /// The lower bound of a column.
+    LowerBound(c_int),
+    /// The upper bound of a column.
+    UpperBound(c_int),
+}
+
+/// How far a relaxed solution violates one of the rows or bounds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    pub target: Relaxable,
+    /// The name of the row or column.
+    pub name: String,
+    pub amount: f64,
+}
+
+/// The result of `Problem::feasibility_relaxation`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relaxation {
+    /// The relaxed solution, in terms of the original problem: the objective is the original
+    /// objective function and the constraints are the values of the original rows. There are no
+    /// duals or reduced costs.
+    pub solution: SolutionData,
+    /// The violation of each row and bound that was allowed to be violated, in the order they
+    /// were given.
+    pub violations: Vec<Violation>,
+    /// The weighted sum of the violations, which the relaxation minimizes.
+    pub total: f64,
+}
+
+impl Problem {
+    /// Finds a solution that violates the rows and bounds in `weights` as little as possible,
+    /// minimizing the sum of their violations times their weights.
+    ///
+    /// This works on a copy of the problem. Each row gets two nonnegative columns, which let its
+    /// value move up and down, and each bound is replaced by a row with a column that lets the
+    /// variable go past it. The columns are the only terms of the objective function. The rows
+    /// and bounds not in `weights` are kept as they are, so the relaxation may still be
+    /// infeasible, in which case `None` is returned, as it is if lpsolve rejects a change or an
+    /// index is out of bounds. The original objective function is ignored, so among the least
+    /// violating solutions the one returned is arbitrary.
+    ///
+    /// Panics if a weight isn't positive.
+    pub fn feasibility_relaxation(&self, weights: &[(Relaxable, f64)]) -> Option<Relaxation> {
+        let (rows, cols) = (self.num_rows(), self.num_cols());
+        let mut problem = self.clone();
+        let infinity = problem.get_infinite();
+        check!(problem.scatter_objective_function(&[], &[]));
+        check!(problem.set_rh(0, 0.0));
+        problem.set_minim();
+
+        // The columns that measure each violation, with their sign in its row.
+        let mut slacks: Vec<Vec<(c_int, f64)>> = Vec::with_capacity(weights.len());
+        for &(target, weight) in weights {
+            assert!(weight > 0.0, "the weight of a violation must be positive, not {}", weight);
+            let (row, signs): (c_int, &[f64]) = match target {
+                Relaxable::Row(row) => {
+                    check!(row >= 1 && row <= rows);
+                    (row, &[1.0, -1.0])
+                }
+                Relaxable::LowerBound(col) => {
+                    let lower = self.get_lower_bound(col)?;
+                    if lower <= -infinity {
+                        slacks.push(Vec::new());
+                        continue;
+                    }
+                    check!(problem.add_constraint_scatter(&[1.0], &[col], lower, ConstraintType::Ge));
+                    check!(problem.set_lower_bound(col, -infinity));
+                    (problem.num_rows(), &[1.0])
+                }
+                Relaxable::UpperBound(col) => {
+                    let upper = self.get_upper_bound(col)?;
+                    if upper >= infinity {
+                        slacks.push(Vec::new());
+                        continue;
+                    }
+                    check!(problem.add_constraint_scatter(&[1.0], &[col], upper, ConstraintType::Le));
+                    check!(problem.set_upper_bound(col, infinity));
+                    (problem.num_rows(), &[-1.0])
+                }
+            };
+            let mut columns = Vec::with_capacity(signs.len());
+            for &sign in signs {
+                check!(problem.add_column_scatter(&[weight, sign], &[0, row]));
+                columns.push((problem.num_cols(), sign));
+            }
+            slacks.push(columns);
+        }
+
+        let status = problem.solve();
+        if status != SolveStatus::Optimal && status != SolveStatus::Suboptimal {
+            return None;
+        }
+        let relaxed = SolutionData::from_problem(&problem);
+        let value = |col: c_int| relaxed.variables[col as usize - 1];
+
+        let mut constraints = relaxed.constraints[..rows as usize].to_vec();
+        let mut violations = Vec::with_capacity(weights.len());
+        for (&(target, _), columns) in weights.iter().zip(&slacks) {
+            if let Relaxable::Row(row) = target {
+                for &(col, sign) in columns {
+                    constraints[row as usize - 1] -= sign * value(col);
+                }
+            }
+            let name = match target {
+                Relaxable::Row(row) => self.get_row_name(row)?,
+                Relaxable::LowerBound(col) | Relaxable::UpperBound(col) => self.get_col_name(col)?,
+            };
+            let amount = columns.iter().map(|&(col, _)| value(col)).sum();
+            violations.push(Violation { target, name, amount });
+        }
+
+        let variables = relaxed.variables[..cols as usize].to_vec();
+        let mut objective = vec![0.0; cols as usize + 1];
+        check!(self.get_row(&mut objective, 0));
+        let objective = self.get_rh(0)? + objective[1..].iter().zip(&variables).map(|(c, x)| c * x).sum::<f64>();
+        Some(Relaxation {
+            solution: SolutionData { status, objective, variables, constraints, duals: None, reduced_costs: None },
+            violations,
+            total: relaxed.objective,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lp_format;
+    use super::Relaxable;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    fn all_close(a: &[f64], b: &[f64]) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(&a, &b)| close(a, b))
+    }
+
+    #[test]
+    fn cheapest_rule_to_bend() {
+        let mut problem = lp_format::parse("
+            max: 3x + 2y;
+            capacity: x + y <= 10;
+            demand_x: x >= 8;
+            demand_y: y >= 6;
+        ").unwrap().to_problem().unwrap();
+        let weights = [(Relaxable::Row(1), 1.0), (Relaxable::Row(2), 5.0), (Relaxable::Row(3), 2.0)];
+        let relaxation = problem.feasibility_relaxation(&weights).unwrap();
+        assert!(close(relaxation.total, 4.0));
+        let names: Vec<_> = relaxation.violations.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, ["capacity", "demand_x", "demand_y"]);
+        let amounts: Vec<_> = relaxation.violations.iter().map(|v| v.amount).collect();
+        assert!(all_close(&amounts, &[4.0, 0.0, 0.0]));
+        let solution = &relaxation.solution;
+        assert!(all_close(&solution.variables, &[8.0, 6.0]));
+        assert!(all_close(&solution.constraints, &[14.0, 8.0, 6.0]));
+        assert!(close(solution.objective, 36.0));
+
+        // With extra capacity dearer, the demand for y gives.
+        let weights = [(Relaxable::Row(1), 3.0), (Relaxable::Row(2), 5.0), (Relaxable::Row(3), 2.0)];
+        let relaxation = problem.feasibility_relaxation(&weights).unwrap();
+        assert!(close(relaxation.total, 8.0));
+        assert!(close(relaxation.violations[2].amount, 4.0));
+        assert!(all_close(&relaxation.solution.variables, &[8.0, 2.0]));
+
+        // The original problem is untouched.
+        assert_eq!(problem.num_rows(), 3);
+        assert_eq!(problem.num_cols(), 2);
+        assert_eq!(problem.solve(), ::SolveStatus::Infeasible);
+    }
+
+    #[test]
+    fn bounds() {
+        let problem = lp_format::parse("
+            min: x;
+            demand: x >= 8;
+            x <= 5;
+        ").unwrap().to_problem().unwrap();
+        let relaxation = problem.feasibility_relaxation(&[(Relaxable::UpperBound(1), 1.0)]).unwrap();
+        assert_eq!(relaxation.violations[0].name, "x");
+        assert!(close(relaxation.violations[0].amount, 3.0));
+        assert!(all_close(&relaxation.solution.variables, &[8.0]));
+
+        // The lower bound of 0 has nothing to do with it.
+        assert_eq!(problem.feasibility_relaxation(&[(Relaxable::LowerBound(1), 1.0)]), None);
+    }
+}
@@ -0,0 +1,285 @@
+//! Model statistics, in the spirit of the `-stat` option of the `lp_solve` driver.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use libc::c_int;
+
+use {ConstraintType, ModelData, Problem, SOSType};
+
+/// Counts and ranges that describe the structure and numerical conditioning of a model.
+///
+/// Rows and columns are identified by their lpsolve index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statistics {
+    pub rows: c_int,
+    pub columns: c_int,
+    /// The number of nonzero coefficients in the constraint matrix.
+    pub nonzeros: c_int,
+
+    pub le_rows: usize,
+    pub ge_rows: usize,
+    pub eq_rows: usize,
+    /// Rows with both a lower and an upper limit that differ.
+    pub ranged_rows: usize,
+    /// Rows without limits.
+    pub free_rows: usize,
+
+    pub continuous_columns: usize,
+    /// Integer columns, including the binary ones.
+    pub integer_columns: usize,
+    /// Integer columns with bounds 0 and 1.
+    pub binary_columns: usize,
+    pub semicontinuous_columns: usize,
+    pub sos1_sets: usize,
+    pub sos2_sets: usize,
+
+    /// The smallest and largest coefficient magnitude in each row, `None` for empty rows.
+    pub row_ranges: Vec<Option<(f64, f64)>>,
+    /// The smallest and largest coefficient magnitude in each column, `None` for empty columns.
+    pub column_ranges: Vec<Option<(f64, f64)>>,
+    /// The smallest and largest coefficient magnitude in the constraint matrix.
+    pub matrix_range: Option<(f64, f64)>,
+    /// The smallest and largest magnitude among the nonzero objective coefficients.
+    pub objective_range: Option<(f64, f64)>,
+    /// The smallest and largest magnitude among the nonzero, finite right hand sides.
+    pub rhs_range: Option<(f64, f64)>,
+
+    /// Rows without coefficients.
+    pub empty_rows: Vec<c_int>,
+    /// Rows with one coefficient, which are bounds in disguise.
+    pub singleton_rows: Vec<c_int>,
+    /// Pairs of rows whose coefficients are multiples of each other, each paired with the first
+    /// such row.
+    pub duplicate_rows: Vec<(c_int, c_int)>,
+    /// Columns without coefficients in the constraint matrix.
+    pub empty_columns: Vec<c_int>,
+    /// Columns without finite bounds.
+    pub free_columns: Vec<c_int>,
+    /// Columns whose bounds are equal.
+    pub fixed_columns: Vec<c_int>,
+}
+
+impl Statistics {
+    /// The ratio of the largest to the smallest coefficient magnitude in the constraint matrix,
+    /// 1 if it is empty. Large ratios make a model harder to solve accurately.
+    pub fn dynamic_range(&self) -> f64 {
+        self.matrix_range.map_or(1.0, |(min, max)| max / min)
+    }
+}
+
+/// Widens `range` to include the magnitude of `value`.
+fn widen(range: &mut Option<(f64, f64)>, value: f64) {
+    let value = value.abs();
+    *range = Some(match *range {
+        Some((min, max)) => (min.min(value), max.max(value)),
+        None => (value, value),
+    });
+}
+
+/// A row's coefficients divided by its first, as a key that is equal for multiples of a row.
+fn direction(coefficients: &[(c_int, f64)]) -> Vec<(c_int, u64)> {
+    let first = coefficients[0].1;
+    coefficients.iter().map(|&(col, value)| (col, (value / first).to_bits())).collect()
+}
+
+impl Problem {
+    /// Counts the rows and columns of the model by type, and collects the ranges of its
+    /// coefficients and the rows and columns that are often the sign of a modeling mistake.
+    ///
+    /// `Statistics` implements `Display` with a human-readable summary.
+    pub fn statistics(&self) -> Statistics {
+        let model = ModelData::from_problem(self);
+        let infinity = model.infinity;
+        let mut stats = Statistics {
+            rows: self.num_rows(),
+            columns: self.num_cols(),
+            nonzeros: self.num_nonzeros(),
+            le_rows: 0,
+            ge_rows: 0,
+            eq_rows: 0,
+            ranged_rows: 0,
+            free_rows: 0,
+            continuous_columns: 0,
+            integer_columns: 0,
+            binary_columns: 0,
+            semicontinuous_columns: 0,
+            sos1_sets: model.sos.iter().filter(|sos| sos.sostype == SOSType::Type1).count(),
+            sos2_sets: model.sos.iter().filter(|sos| sos.sostype == SOSType::Type2).count(),
+            row_ranges: Vec::with_capacity(model.rows.len()),
+            column_ranges: vec![None; model.columns.len()],
+            matrix_range: None,
+            objective_range: None,
+            rhs_range: None,
+            empty_rows: Vec::new(),
+            singleton_rows: Vec::new(),
+            duplicate_rows: Vec::new(),
+            empty_columns: Vec::new(),
+            free_columns: Vec::new(),
+            fixed_columns: Vec::new(),
+        };
+
+        let mut directions: HashMap<Vec<(c_int, u64)>, c_int> = HashMap::new();
+        for (i, row) in model.rows.iter().enumerate() {
+            let index = i as c_int + 1;
+            let (lower, upper) = row.limits(infinity);
+            match (lower > -infinity, upper < infinity) {
+                (true, true) if lower < upper => stats.ranged_rows += 1,
+                (true, true) => stats.eq_rows += 1,
+                (false, false) => stats.free_rows += 1,
+                _ if row.kind == ConstraintType::Ge => stats.ge_rows += 1,
+                _ => stats.le_rows += 1,
+            }
+            for &limit in &[lower, upper] {
+                if limit != 0.0 && limit.abs() < infinity {
+                    widen(&mut stats.rhs_range, limit);
+                }
+            }
+
+            let mut range = None;
+            for &(col, value) in &row.coefficients {
+                widen(&mut range, value);
+                widen(&mut stats.column_ranges[col as usize - 1], value);
+                widen(&mut stats.matrix_range, value);
+            }
+            stats.row_ranges.push(range);
+            match row.coefficients.len() {
+                0 => stats.empty_rows.push(index),
+                1 => stats.singleton_rows.push(index),
+                _ => {}
+            }
+            if !row.coefficients.is_empty() {
+                let first = *directions.entry(direction(&row.coefficients)).or_insert(index);
+                if first != index {
+                    stats.duplicate_rows.push((first, index));
+                }
+            }
+        }
+
+        for (j, column) in model.columns.iter().enumerate() {
+            let index = j as c_int + 1;
+            if column.integer {
+                stats.integer_columns += 1;
+                if column.lower == 0.0 && column.upper == 1.0 {
+                    stats.binary_columns += 1;
+                }
+            } else {
+                stats.continuous_columns += 1;
+            }
+            if column.semicontinuous {
+                stats.semicontinuous_columns += 1;
+            }
+            if stats.column_ranges[j].is_none() {
+                stats.empty_columns.push(index);
+            }
+            if column.lower <= -infinity && column.upper >= infinity {
+                stats.free_columns.push(index);
+            } else if column.lower == column.upper {
+                stats.fixed_columns.push(index);
+            }
+        }
+        for &(_, value) in &model.objective {
+            widen(&mut stats.objective_range, value);
+        }
+        stats
+    }
+}
+
+/// Writes a list of indices, shortened after the first few.
+fn write_indices<T: fmt::Debug>(f: &mut fmt::Formatter, label: &str, indices: &[T]) -> fmt::Result {
+    const SHOWN: usize = 10;
+    if indices.is_empty() {
+        return Ok(());
+    }
+    write!(f, "{:<18}{}:", label, indices.len())?;
+    for index in indices.iter().take(SHOWN) {
+        write!(f, " {:?}", index)?;
+    }
+    if indices.len() > SHOWN {
+        write!(f, " ...")?;
+    }
+    writeln!(f)
+}
+
+fn write_range(f: &mut fmt::Formatter, label: &str, range: Option<(f64, f64)>) -> fmt::Result {
+    match range {
+        Some((min, max)) => writeln!(f, "{:<18}[{:e}, {:e}]", label, min, max),
+        None => writeln!(f, "{:<18}none", label),
+    }
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:<18}{} ({} <=, {} >=, {} ==, {} ranged, {} free)", "rows", self.rows,
+                 self.le_rows, self.ge_rows, self.eq_rows, self.ranged_rows, self.free_rows)?;
+        writeln!(f, "{:<18}{} ({} continuous, {} integer of which {} binary, {} semicontinuous)", "columns",
+                 self.columns, self.continuous_columns, self.integer_columns, self.binary_columns,
+                 self.semicontinuous_columns)?;
+        let density = if self.rows > 0 && self.columns > 0 {
+            100.0 * f64::from(self.nonzeros) / (f64::from(self.rows) * f64::from(self.columns))
+        } else {
+            0.0
+        };
+        writeln!(f, "{:<18}{} ({:.2}% dense)", "nonzeros", self.nonzeros, density)?;
+        if self.sos1_sets + self.sos2_sets > 0 {
+            writeln!(f, "{:<18}{} SOS1, {} SOS2", "sets", self.sos1_sets, self.sos2_sets)?;
+        }
+        write_range(f, "matrix range", self.matrix_range)?;
+        write_range(f, "objective range", self.objective_range)?;
+        write_range(f, "rhs range", self.rhs_range)?;
+        writeln!(f, "{:<18}{:e}", "dynamic range", self.dynamic_range())?;
+        write_indices(f, "empty rows", &self.empty_rows)?;
+        write_indices(f, "singleton rows", &self.singleton_rows)?;
+        write_indices(f, "duplicate rows", &self.duplicate_rows)?;
+        write_indices(f, "empty columns", &self.empty_columns)?;
+        write_indices(f, "free columns", &self.free_columns)?;
+        write_indices(f, "fixed columns", &self.fixed_columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lp_format;
+
+    #[test]
+    fn health_check() {
+        let problem = lp_format::parse("
+            max: 3x + 2y - 0.5z;
+            c1: x + y + z <= 10;
+            c2: 2x + 2y + 2z >= 4;
+            c3: -3 <= x - y <= 3;
+            c4: 1000 y = 500;
+            c5: 0.001 x + w >= -1e30;
+            x <= 1;
+            z >= -1e30;
+            w = 2;
+            v <= 1;
+            y <= 8;
+            int x, v;
+            sec y;
+            sos1
+            s1: x:1, y:2;
+        ").unwrap().to_problem().unwrap();
+        let stats = problem.statistics();
+        assert_eq!((stats.rows, stats.columns, stats.nonzeros), (5, 5, 11));
+        let kinds = (stats.le_rows, stats.ge_rows, stats.eq_rows, stats.ranged_rows, stats.free_rows);
+        assert_eq!(kinds, (1, 1, 1, 1, 1));
+        assert_eq!((stats.continuous_columns, stats.integer_columns, stats.binary_columns), (3, 2, 2));
+        assert_eq!((stats.semicontinuous_columns, stats.sos1_sets, stats.sos2_sets), (1, 1, 0));
+        assert_eq!(stats.matrix_range, Some((0.001, 1000.0)));
+        assert_eq!(stats.objective_range, Some((0.5, 3.0)));
+        assert_eq!(stats.rhs_range, Some((3.0, 500.0)));
+        assert_eq!(stats.row_ranges[1], Some((2.0, 2.0)));
+        assert_eq!(stats.column_ranges[1], Some((1.0, 1000.0)));
+        assert_eq!(stats.dynamic_range(), 1e6);
+        assert_eq!(stats.singleton_rows, [4]);
+        assert_eq!(stats.duplicate_rows, [(1, 2)]);
+        assert_eq!(stats.empty_columns, [5]);
+        assert_eq!(stats.free_columns, [3]);
+        assert_eq!(stats.fixed_columns, [4]);
+
+        let summary = stats.to_string();
+        assert!(summary.starts_with("rows              5 (1 <=, 1 >=, 1 ==, 1 ranged, 1 free)\n"));
+        assert!(summary.contains("duplicate rows    1: (1, 2)\n"));
+    }
+}
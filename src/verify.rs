@@ -0,0 +1,218 @@
+//! Checking solutions against a model.
+
+use std::fmt;
+
+use libc::c_int;
+
+use {ModelData, Problem, SOSType};
+
+/// A requirement of the model that a solution can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Requirement {
+    /// The limits of a row.
+    Row(c_int),
+    /// The lower bound of a column.
+    LowerBound(c_int),
+    /// The upper bound of a column.
+    UpperBound(c_int),
+    /// That a column is integer.
+    Integer(c_int),
+    /// That a semicontinuous column is 0 or within its bounds.
+    Semicontinuous(c_int),
+    /// That at most as many neighbouring members of a special ordered set as its type are
+    /// nonzero. The index is the position of the set, from 0.
+    Sos(usize),
+}
+
+/// A requirement a solution fails, and by how much.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    pub requirement: Requirement,
+    /// The name of the row, column or set.
+    pub name: String,
+    /// How far the solution is from meeting the requirement: the distance to the violated limit
+    /// or bound, to the nearest integer or allowed value, or the total magnitude of the members of
+    /// a set that would have to be 0.
+    pub amount: f64,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let what = match self.requirement {
+            Requirement::Row(_) => "row",
+            Requirement::LowerBound(_) => "lower bound of",
+            Requirement::UpperBound(_) => "upper bound of",
+            Requirement::Integer(_) => "integrality of",
+            Requirement::Semicontinuous(_) => "semicontinuity of",
+            Requirement::Sos(_) => "SOS",
+        };
+        write!(f, "{} {} violated by {}", what, self.name, self.amount)
+    }
+}
+
+/// The result of `Problem::verify`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationReport {
+    /// Every requirement the solution fails by more than the tolerance, with the rows first, then
+    /// the columns, then the sets.
+    pub failures: Vec<Failure>,
+}
+
+impl VerificationReport {
+    /// Whether the solution meets every requirement.
+    pub fn is_feasible(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// The largest amount by which a requirement is failed, 0 if none are.
+    pub fn max_violation(&self) -> f64 {
+        self.failures.iter().map(|failure| failure.amount).fold(0.0, f64::max)
+    }
+}
+
+impl fmt::Display for VerificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.failures.is_empty() {
+            return writeln!(f, "feasible");
+        }
+        for failure in &self.failures {
+            writeln!(f, "{}", failure)?;
+        }
+        Ok(())
+    }
+}
+
+/// How far `value` is below `lower` or above `upper`, ignoring infinite limits.
+fn outside(value: f64, lower: f64, upper: f64, infinity: f64) -> f64 {
+    if lower > -infinity && value < lower {
+        lower - value
+    } else if upper < infinity && value > upper {
+        value - upper
+    } else {
+        0.0
+    }
+}
+
+/// The total magnitude of the members of a set outside the best window of `size` neighbours.
+fn outside_window(values: &[f64], size: usize) -> f64 {
+    let total: f64 = values.iter().map(|v| v.abs()).sum();
+    let best = values.windows(size.min(values.len()).max(1))
+        .map(|window| window.iter().map(|v| v.abs()).sum::<f64>())
+        .fold(0.0, f64::max);
+    total - best
+}
+
+impl Problem {
+    /// Checks `values`, one per column, against the rows, bounds, integrality, semicontinuity
+    /// and special ordered sets of the model, and reports every requirement they fail by more
+    /// than `tolerance`.
+    ///
+    /// This is computed in Rust from the model, without solving it, so it can check a solution
+    /// from anywhere, such as a warm start. The tolerance is absolute. A member of a special
+    /// ordered set counts as nonzero if it is further than `tolerance` from 0.
+    ///
+    /// Panics if `values` doesn't have exactly as many elements as the model has columns.
+    pub fn verify(&self, values: &[f64], tolerance: f64) -> VerificationReport {
+        assert!(values.len() == self.num_cols() as usize, "a solution needs a value for every column");
+        let model = ModelData::from_problem(self);
+        let infinity = model.infinity;
+        let column_name = |col: c_int| self.get_col_name(col).unwrap_or_default();
+        let mut failures = Vec::new();
+
+        for (i, row) in model.rows.iter().enumerate() {
+            let row_index = i as c_int + 1;
+            let activity: f64 = row.coefficients.iter().map(|&(j, a)| a * values[j as usize - 1]).sum();
+            let (lower, upper) = row.limits(infinity);
+            let amount = outside(activity, lower, upper, infinity);
+            if amount > tolerance {
+                let name = self.get_row_name(row_index).unwrap_or_default();
+                failures.push(Failure { requirement: Requirement::Row(row_index), name, amount });
+            }
+        }
+
+        for (j, (column, &value)) in model.columns.iter().zip(values).enumerate() {
+            let col = j as c_int + 1;
+            let mut fail = |requirement, amount| if amount > tolerance {
+                failures.push(Failure { requirement, name: column_name(col), amount });
+            };
+            if column.semicontinuous {
+                let amount = if value.abs() <= tolerance {
+                    0.0
+                } else {
+                    outside(value, column.lower, column.upper, infinity).min(value.abs())
+                };
+                fail(Requirement::Semicontinuous(col), amount);
+            } else {
+                fail(Requirement::LowerBound(col), outside(value, column.lower, infinity, infinity));
+                fail(Requirement::UpperBound(col), outside(value, -infinity, column.upper, infinity));
+            }
+            if column.integer {
+                fail(Requirement::Integer(col), (value - value.round()).abs());
+            }
+        }
+
+        for (i, sos) in model.sos.iter().enumerate() {
+            let members: Vec<f64> = sos.variables.iter()
+                .map(|&col| values[col as usize - 1])
+                .map(|value| if value.abs() <= tolerance { 0.0 } else { value })
+                .collect();
+            let size = match sos.sostype {
+                SOSType::Type1 => 1,
+                SOSType::Type2 => 2,
+            };
+            let amount = outside_window(&members, size);
+            if amount > 0.0 {
+                failures.push(Failure { requirement: Requirement::Sos(i), name: sos.name.clone(), amount });
+            }
+        }
+
+        VerificationReport { failures }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lp_format;
+    use super::Requirement;
+
+    #[test]
+    fn every_kind_of_failure() {
+        let problem = lp_format::parse("
+            max: x + y + n + s;
+            cap: x + y <= 10;
+            band: -5 <= x - y <= 5;
+            x <= 8;
+            n <= 4;
+            s <= 6;
+            s >= 2;
+            int n;
+            sec s;
+            sos2
+            neighbours: x:1, y:2, n:3;
+        ").unwrap().to_problem().unwrap();
+        let names: Vec<_> = (1..=4).map(|col| problem.get_col_name(col).unwrap()).collect();
+        assert_eq!(names, ["x", "y", "n", "s"]);
+
+        let report = problem.verify(&[3.0, 7.0, 0.0, 4.0], 1e-9);
+        assert!(report.is_feasible());
+        assert_eq!(report.to_string(), "feasible\n");
+        assert!(problem.verify(&[0.0, 0.0, 0.0, 0.0], 1e-9).is_feasible());
+
+        let report = problem.verify(&[9.0, 2.0, 1.5, 1.0], 1e-9);
+        let failures: Vec<_> = report.failures.iter().map(|f| (f.requirement, f.amount)).collect();
+        assert_eq!(failures, [
+            (Requirement::Row(1), 1.0),
+            (Requirement::Row(2), 2.0),
+            (Requirement::UpperBound(1), 1.0),
+            (Requirement::Integer(3), 0.5),
+            (Requirement::Semicontinuous(4), 1.0),
+            (Requirement::Sos(0), 1.5),
+        ]);
+        assert_eq!(report.max_violation(), 2.0);
+        assert_eq!(report.failures[1].to_string(), "row band violated by 2");
+        assert_eq!(report.failures[5].to_string(), "SOS neighbours violated by 1.5");
+
+        // Within the tolerance, small violations pass.
+        assert!(problem.verify(&[3.0, 7.0 + 1e-7, 1e-7, 4.0], 1e-6).is_feasible());
+    }
+}